@@ -0,0 +1,206 @@
+//! The `checked_*` and `saturating_*` methods shared by the amount types.
+//!
+//! Every macro takes the generic parameters of the type in brackets, the type `$ty`, its raw integer `$int`,
+//! the constructor `$from` and the accessor `$as`, and `$example`, the concrete type used in the doc examples.
+
+/// Implements `checked_add`, `checked_sub`, `saturating_add` and `saturating_sub`.
+macro_rules! impl_checked_add_sub {
+    ([$($generics:tt)*] $ty:ty, $int:ident, $from:ident, $as:ident, $example:literal) => {
+        impl<$($generics)*> $ty {
+            /// Checked integer addition. Computes self + rhs, returning None if overflow occurred.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(2).checked_add(Amount::", stringify!($from), "(3)), Some(Amount::", stringify!($from), "(5)));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(", stringify!($int), "::MAX).checked_add(Amount::", stringify!($from), "(1)), None);")]
+            /// ```
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.$as().checked_add(rhs.$as()) {
+                    Some(value) => Some(Self::$from(value)),
+                    None => None,
+                }
+            }
+
+            /// Checked integer subtraction. Computes self - rhs, returning None if overflow occurred.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(3).checked_sub(Amount::", stringify!($from), "(2)), Some(Amount::", stringify!($from), "(1)));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(2).checked_sub(Amount::", stringify!($from), "(3)), None);")]
+            /// ```
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.$as().checked_sub(rhs.$as()) {
+                    Some(value) => Some(Self::$from(value)),
+                    None => None,
+                }
+            }
+
+            /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(5).saturating_add(Amount::", stringify!($from), "(5)), Amount::", stringify!($from), "(10));")]
+            #[doc = concat!("let max = Amount::", stringify!($from), "(", stringify!($int), "::MAX);")]
+            #[doc = concat!("assert_eq!(max.saturating_add(Amount::", stringify!($from), "(1)), max);")]
+            /// ```
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                Self::$from(self.$as().saturating_add(rhs.$as()))
+            }
+
+            /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(5).saturating_sub(Amount::", stringify!($from), "(2)), Amount::", stringify!($from), "(3));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(1).saturating_sub(Amount::", stringify!($from), "(2)), Amount::", stringify!($from), "(0));")]
+            /// ```
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                Self::$from(self.$as().saturating_sub(rhs.$as()))
+            }
+        }
+    };
+}
+
+/// Implements `checked_mul`, `checked_div`, `saturating_mul` and `saturating_div` by a raw `$int`.
+macro_rules! impl_checked_mul_div {
+    ([$($generics:tt)*] $ty:ty, $int:ident, $from:ident, $as:ident, $example:literal) => {
+        impl<$($generics)*> $ty {
+            /// Checked integer multiplication. Computes self * rhs, returning None if overflow occurred.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(2).checked_mul(2), Some(Amount::", stringify!($from), "(4)));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(", stringify!($int), "::MAX).checked_mul(2), None);")]
+            /// ```
+            pub const fn checked_mul(self, rhs: $int) -> Option<Self> {
+                match self.$as().checked_mul(rhs) {
+                    Some(value) => Some(Self::$from(value)),
+                    None => None,
+                }
+            }
+
+            /// Checked integer division. Computes self / rhs, returning None if rhs == 0.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(10).checked_div(2), Some(Amount::", stringify!($from), "(5)));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(2).checked_div(0), None);")]
+            /// ```
+            pub const fn checked_div(self, rhs: $int) -> Option<Self> {
+                match self.$as().checked_div(rhs) {
+                    Some(value) => Some(Self::$from(value)),
+                    None => None,
+                }
+            }
+
+            /// Saturating integer multiplication. Computes self * rhs, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(2).saturating_mul(5), Amount::", stringify!($from), "(10));")]
+            #[doc = concat!("let max = Amount::", stringify!($from), "(", stringify!($int), "::MAX);")]
+            /// assert_eq!(max.saturating_mul(2), max);
+            /// ```
+            pub const fn saturating_mul(self, rhs: $int) -> Self {
+                Self::$from(self.$as().saturating_mul(rhs))
+            }
+
+            /// Saturating integer division. Computes self / rhs, saturating at the numeric bounds instead of overflowing.
+            /// Division by zero gives zero.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(10).saturating_div(2), Amount::", stringify!($from), "(5));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(10).saturating_div(0), Amount::", stringify!($from), "(0));")]
+            /// ```
+            pub const fn saturating_div(self, rhs: $int) -> Self {
+                if rhs == 0 {
+                    return Self::$from(0);
+                }
+                Self::$from(self.$as().saturating_div(rhs))
+            }
+        }
+    };
+}
+
+/// Implements `checked_mul_div`, `mul_ratio` and `checked_sum` for a `u128` amount.
+/// Relies on `checked_add` from [`impl_checked_add_sub`].
+macro_rules! impl_checked_mul_ratio {
+    ([$($generics:tt)*] $ty:ty, $from:ident, $as:ident, $example:literal) => {
+        impl<$($generics)*> $ty {
+            /// Checked multiplication by a fraction. Computes self * numerator / denominator, rounding the result
+            /// according to `rounding` and returning None if denominator == 0 or the result overflows.
+            ///
+            /// The intermediate product is computed with 256 bits, so it never overflows on its own.
+            ///
+            /// # Examples
+            /// ```
+            /// use unc_token::RoundingMode;
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("let amount = Amount::", stringify!($from), "(u128::MAX / 2);")]
+            /// assert_eq!(amount.checked_mul(3), None);
+            #[doc = concat!("assert_eq!(amount.checked_mul_div(3, 6, RoundingMode::Down), Some(Amount::", stringify!($from), "(u128::MAX / 4)));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(10).checked_mul_div(1, 3, RoundingMode::Up), Some(Amount::", stringify!($from), "(4)));")]
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(10).checked_mul_div(1, 0, RoundingMode::Up), None);")]
+            /// ```
+            pub const fn checked_mul_div(
+                self,
+                numerator: u128,
+                denominator: u128,
+                rounding: $crate::RoundingMode,
+            ) -> Option<Self> {
+                let Some((quotient, remainder)) =
+                    $crate::math::mul_div(self.$as(), numerator, denominator)
+                else {
+                    return None;
+                };
+                if rounding.rounds_up(quotient, remainder, denominator) {
+                    match quotient.checked_add(1) {
+                        Some(value) => Some(Self::$from(value)),
+                        None => None,
+                    }
+                } else {
+                    Some(Self::$from(quotient))
+                }
+            }
+
+            /// Checked multiplication by a `(numerator, denominator)` ratio, see `checked_mul_div`.
+            ///
+            /// # Examples
+            /// ```
+            /// use unc_token::RoundingMode;
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            /// let interest_rate = (5, 100);
+            #[doc = concat!("assert_eq!(Amount::", stringify!($from), "(200).mul_ratio(interest_rate, RoundingMode::Down), Some(Amount::", stringify!($from), "(10)));")]
+            /// ```
+            pub const fn mul_ratio(
+                self,
+                ratio: (u128, u128),
+                rounding: $crate::RoundingMode,
+            ) -> Option<Self> {
+                self.checked_mul_div(ratio.0, ratio.1, rounding)
+            }
+
+            /// Checked sum of an iterator of amounts. Computes the total, returning None if overflow occurred.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("type Amount = unc_token::", $example, ";")]
+            #[doc = concat!("let balances = [Amount::", stringify!($from), "(1), Amount::", stringify!($from), "(2)];")]
+            #[doc = concat!("assert_eq!(Amount::checked_sum(balances), Some(Amount::", stringify!($from), "(3)));")]
+            #[doc = concat!("assert_eq!(Amount::checked_sum([Amount::", stringify!($from), "(u128::MAX), Amount::", stringify!($from), "(1)]), None);")]
+            /// ```
+            pub fn checked_sum<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self> {
+                iter.into_iter().try_fold(Self::$from(0), Self::checked_add)
+            }
+        }
+    };
+}
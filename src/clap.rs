@@ -0,0 +1,369 @@
+//! A [`clap`] value parser for [`UncToken`] arguments of command line tools.
+//!
+//! With the **clap** feature `UncToken` arguments are parsed by [`UncTokenValueParser`], so with clap derive
+//! a plain `amount: UncToken` field accepts the same values as `UncToken::from_str`, e.g. `1.5 UNC` or `500mUNC`.
+//! Unknown units are reported with the list of accepted units and a suggestion for typos:
+//!
+//! ```text
+//! error: invalid value '1.5 UNX' for '--amount <AMOUNT>': invalid token unit: 1.5 UNX
+//!
+//!   tip: a similar unit exists: 'UNC'
+//! ```
+//!
+//! Use [`UncTokenValueParser::min`] and [`UncTokenValueParser::max`] to bound the accepted amounts and
+//! [`UNITS_HELP`] to list the accepted units in `--help`.
+//!
+//! # Examples
+//! ```
+//! use clap::{Arg, Command};
+//! use unc_token::clap::{UncTokenValueParser, UNITS_HELP};
+//! use unc_token::UncToken;
+//!
+//! let cmd = Command::new("transfer")
+//!     .arg(Arg::new("amount").long("amount").help(UNITS_HELP).value_parser(clap::value_parser!(UncToken)))
+//!     .arg(
+//!         Arg::new("fee")
+//!             .long("fee")
+//!             .value_parser(UncTokenValueParser::new().max(UncToken::from_milliunc(10))),
+//!     );
+//!
+//! let matches = cmd.clone().try_get_matches_from(["transfer", "--amount", "1.5 UNC"]).unwrap();
+//! assert_eq!(matches.get_one::<UncToken>("amount"), Some(&UncToken::from_milliunc(1_500)));
+//!
+//! let err = cmd.clone().try_get_matches_from(["transfer", "--amount", "1.5 UNX"]).unwrap_err();
+//! assert!(err.to_string().contains("a similar unit exists: 'UNC'"));
+//!
+//! let err = cmd.try_get_matches_from(["transfer", "--fee", "1 UNC"]).unwrap_err();
+//! assert!(err.to_string().contains("must be at most 0.01 UNC"));
+//! ```
+
+use std::ffi::OsStr;
+use std::fmt::Write;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+
+use crate::trait_impls::from_str::UNITS;
+use crate::{UncToken, UncTokenError};
+
+/// The help text that lists the accepted units, for `#[arg(help = unc_token::clap::UNITS_HELP)]`.
+pub const UNITS_HELP: &str = match core::str::from_utf8(&UNITS_HELP_BYTES) {
+    Ok(help) => help,
+    Err(_) => panic!("the units help is not valid UTF-8"),
+};
+
+const UNITS_HELP_BYTES: [u8; UNITS_HELP_LEN] = units_help::<UNITS_HELP_LEN>().0;
+
+const UNITS_HELP_LEN: usize = units_help::<0>().1;
+
+/// Writes the help text with the accepted [`UNITS`] into the first `N` bytes of the array, grouping
+/// the units of the same size: `UNC (N), kiloUNC (kUNC), ... and attoUNC (aUNC, aN)`.
+/// Returns the array and the length of the whole text, so it can be called with `N = 0` to measure it.
+const fn units_help<const N: usize>() -> ([u8; N], usize) {
+    let help = ([0; N], 0);
+    let mut help = push(
+        help,
+        "An amount of tokens with a unit, e.g. \"1.5 UNC\". Accepted units: ",
+    );
+    let last_group = UNITS[UNITS.len() - 1].1;
+    let mut index = 0;
+    while index < UNITS.len() {
+        let (name, base_units) = UNITS[index];
+        let separator = if starts_group(index) {
+            match index {
+                0 => "",
+                _ if base_units == last_group => " and ",
+                _ => ", ",
+            }
+        } else if starts_group(index - 1) {
+            " ("
+        } else {
+            ", "
+        };
+        help = push(push(help, separator), name);
+        if !starts_group(index) && (index + 1 == UNITS.len() || starts_group(index + 1)) {
+            help = push(help, ")");
+        }
+        index += 1;
+    }
+    help
+}
+
+/// Returns whether the unit at `index` of [`UNITS`] is the full name of a unit, not its abbreviation.
+const fn starts_group(index: usize) -> bool {
+    index == 0 || UNITS[index - 1].1 != UNITS[index].1
+}
+
+/// Appends `s` to the text of `len` bytes in `buf`, dropping the bytes that do not fit.
+const fn push<const N: usize>((mut buf, mut len): ([u8; N], usize), s: &str) -> ([u8; N], usize) {
+    let bytes = s.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        if len < N {
+            buf[len] = bytes[index];
+        }
+        len += 1;
+        index += 1;
+    }
+    (buf, len)
+}
+
+/// Parses `UncToken` arguments with `UncToken::from_str`, optionally rejecting amounts outside of
+/// the [`min`](UncTokenValueParser::min)..=[`max`](UncTokenValueParser::max) bounds.
+///
+/// It is the default value parser of `UncToken`, so `clap::value_parser!(UncToken)` creates it without bounds.
+///
+/// # Examples
+/// ```
+/// use clap::Parser;
+/// use unc_token::clap::UncTokenValueParser;
+/// use unc_token::UncToken;
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     #[arg(long, help = unc_token::clap::UNITS_HELP)]
+///     amount: UncToken,
+///     #[arg(long, value_parser = UncTokenValueParser::new().min(UncToken::from_milliunc(1)))]
+///     deposit: UncToken,
+/// }
+///
+/// let cli = Cli::try_parse_from(["cli", "--amount", "2 UNC", "--deposit", "0.5 UNC"]).unwrap();
+/// assert_eq!(cli.amount, UncToken::from_unc(2));
+/// assert!(Cli::try_parse_from(["cli", "--amount", "2 UNC", "--deposit", "1 aN"]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UncTokenValueParser {
+    min: Option<UncToken>,
+    max: Option<UncToken>,
+}
+
+impl UncTokenValueParser {
+    /// `new` is a function that creates a parser that accepts any amount.
+    pub const fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// `min` is a function that makes the parser reject amounts below `min`.
+    pub const fn min(mut self, min: UncToken) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// `max` is a function that makes the parser reject amounts above `max`.
+    pub const fn max(mut self, max: UncToken) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl TypedValueParser for UncTokenValueParser {
+    type Value = UncToken;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+        let Some(value) = value.to_str() else {
+            return Err(invalid_value(
+                cmd,
+                &arg,
+                &value.to_string_lossy(),
+                "invalid UTF-8",
+                "",
+            ));
+        };
+
+        let amount = value.parse::<UncToken>().map_err(|err| {
+            let tips = match &err {
+                UncTokenError::InvalidTokenUnit(unit) => unit_tips(&value[unit.span()]),
+                UncTokenError::InvalidTokensAmount(_) => String::new(),
+            };
+            invalid_value(cmd, &arg, value, &err.display(value).to_string(), &tips)
+        })?;
+        if let Some(min) = self.min.filter(|&min| amount < min) {
+            let reason = format!("amount must be at least {:#}", min);
+            return Err(invalid_value(cmd, &arg, value, &reason, ""));
+        }
+        if let Some(max) = self.max.filter(|&max| amount > max) {
+            let reason = format!("amount must be at most {:#}", max);
+            return Err(invalid_value(cmd, &arg, value, &reason, ""));
+        }
+        Ok(amount)
+    }
+}
+
+/// Creates the error of clap value parsers, in the format of the errors of the built-in parsers.
+fn invalid_value(cmd: &Command, arg: &str, value: &str, reason: &str, tips: &str) -> Error {
+    let message = format!(
+        "invalid value '{}' for '{}': {}\n{}",
+        value, arg, reason, tips
+    );
+    Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+}
+
+/// The tips shown for an unknown `unit`: the most similar unit, if any, and the list of accepted units.
+fn unit_tips(unit: &str) -> String {
+    let mut tips = String::from("\n");
+    if let Some(suggestion) = suggest_unit(unit) {
+        let _ = writeln!(tips, "  tip: a similar unit exists: '{}'", suggestion);
+    }
+    let names: Vec<&str> = UNITS.iter().map(|&(name, _)| name).collect();
+    let _ = writeln!(tips, "  tip: accepted units are {}", names.join(", "));
+    tips
+}
+
+/// Returns the accepted unit that is the closest to `unit` by the edit distance, if it is close enough
+/// to be a typo: at most one edit for every three characters.
+fn suggest_unit(unit: &str) -> Option<&'static str> {
+    if unit.is_empty() {
+        return None;
+    }
+    let unit = unit.to_ascii_lowercase();
+    UNITS
+        .iter()
+        .map(|&(name, _)| (edit_distance(&unit, &name.to_ascii_lowercase()), name))
+        .filter(|&(distance, name)| distance * 3 <= unit.chars().count().max(name.chars().count()))
+        // `min_by_key` keeps the first of the equally close units, so the more common units win.
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, name)| name)
+}
+
+/// The Levenshtein distance between `a` and `b`: the number of inserted, removed or replaced characters
+/// that turns `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // `row[j]` is the distance between the processed prefix of `a` and the first `j` characters of `b`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let replaced = diagonal + usize::from(a_char != b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = replaced.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use clap::{Arg, Command};
+
+    use super::{edit_distance, suggest_unit, UncTokenValueParser, UNITS_HELP};
+    use crate::UncToken;
+
+    fn command(parser: UncTokenValueParser) -> Command {
+        Command::new("cli").arg(Arg::new("amount").long("amount").value_parser(parser))
+    }
+
+    fn parse(parser: UncTokenValueParser, value: &str) -> Result<UncToken, String> {
+        command(parser)
+            .try_get_matches_from(["cli", "--amount", value])
+            .map(|matches| *matches.get_one::<UncToken>("amount").unwrap())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn units_help() {
+        assert_eq!(
+            UNITS_HELP,
+            "An amount of tokens with a unit, e.g. \"1.5 UNC\". Accepted units: UNC (N), kiloUNC (kUNC), \
+             milliUNC (mUNC), microUNC (\u{b5}UNC, \u{3bc}UNC, uUNC), nanoUNC (nUNC), picoUNC (pUNC), \
+             femtoUNC (fUNC) and attoUNC (aUNC, aN)"
+        );
+    }
+
+    #[test]
+    fn parse_amounts() {
+        let parser = UncTokenValueParser::new();
+        assert_eq!(parse(parser, "1.5 UNC"), Ok(UncToken::from_milliunc(1_500)));
+        assert_eq!(parse(parser, "500mUNC"), Ok(UncToken::from_milliunc(500)));
+
+        let err = parse(parser, "1.5 UNX").unwrap_err();
+        assert!(
+            err.starts_with(
+                "error: invalid value '1.5 UNX' for '--amount <amount>': invalid token unit: 1.5 UNX\n\n  \
+                 tip: a similar unit exists: 'UNC'\n  tip: accepted units are UNC, N, kiloUNC"
+            ),
+            "{}",
+            err
+        );
+        let err = parse(parser, "1..5 UNC").unwrap_err();
+        assert!(
+            err.starts_with(
+                "error: invalid value '1..5 UNC' for '--amount <amount>': invalid tokens amount: invalid number: 1..5\n"
+            ),
+            "{}",
+            err
+        );
+        assert!(!err.contains("tip"), "{}", err);
+    }
+
+    #[test]
+    fn parse_bounded_amounts() {
+        let parser = UncTokenValueParser::new()
+            .min(UncToken::from_milliunc(1))
+            .max(UncToken::from_unc(10));
+        assert_eq!(parse(parser, "1 mUNC"), Ok(UncToken::from_milliunc(1)));
+        assert_eq!(parse(parser, "10 UNC"), Ok(UncToken::from_unc(10)));
+        let err = parse(parser, "0.5 mUNC").unwrap_err();
+        assert!(err.contains("amount must be at least 0.001 UNC"), "{}", err);
+        let err = parse(parser, "10.5 UNC").unwrap_err();
+        assert!(err.contains("amount must be at most 10 UNC"), "{}", err);
+
+        // The bounds are printed exactly, as the rounded display could contradict the input.
+        let parser = UncTokenValueParser::new().max(UncToken::from_milliunc(1_004));
+        let err = parse(parser, "1.005 UNC").unwrap_err();
+        assert!(err.contains("amount must be at most 1.004 UNC"), "{}", err);
+        let parser = UncTokenValueParser::new().min(UncToken::from_attounc(1));
+        let err = parse(parser, "0 UNC").unwrap_err();
+        assert!(
+            err.contains("amount must be at least 0.000000000000000000000001 UNC"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn default_value_parser() {
+        let cmd = Command::new("cli").arg(
+            Arg::new("amount")
+                .long("amount")
+                .value_parser(clap::value_parser!(UncToken)),
+        );
+        let matches = cmd
+            .try_get_matches_from(["cli", "--amount", "2 kUNC"])
+            .unwrap();
+        assert_eq!(
+            matches.get_one::<UncToken>("amount"),
+            Some(&UncToken::from_unc(2_000))
+        );
+    }
+
+    #[test]
+    fn suggest_units() {
+        assert_eq!(suggest_unit("UNX"), Some("UNC"));
+        assert_eq!(suggest_unit("unx"), Some("UNC"));
+        assert_eq!(suggest_unit("miliUNC"), Some("milliUNC"));
+        assert_eq!(suggest_unit("NEAR"), None);
+        assert_eq!(suggest_unit("X"), None);
+        assert_eq!(suggest_unit(""), None);
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("unc", ""), 3);
+        assert_eq!(edit_distance("unx", "unc"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("\u{b5}unc", "uunc"), 1);
+    }
+}
@@ -1,18 +1,349 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+use core::ops::Range;
+
+/// The part of the input that caused a parsing error, as byte ranges of the parsed input:
+/// the [`quote`](Self::quote) shown in the error message and the [`span`](Self::span) the error points at.
+///
+/// The input is not copied, so parsing errors never allocate. Pass the parsed input to the `display`
+/// or `diagnostic` methods of the errors to show the quoted text; their `Display` implementations,
+/// which do not have the input, show the span instead.
+///
+/// # Examples
+/// ```
+/// use unc_token::{UncToken, UncTokenError};
+/// let input = "1.5 UAH";
+/// let err = input.parse::<UncToken>().unwrap_err();
+/// assert_eq!(err.display(input).to_string(), "invalid token unit: 1.5 UAH");
+/// assert_eq!(err.to_string(), "invalid token unit at bytes 4..7");
+/// assert_eq!(err.span(), 4..7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputFragment {
+    quote_start: usize,
+    quote_end: usize,
+    start: usize,
+    end: usize,
+}
+
+impl InputFragment {
+    /// `new` is a function that creates a fragment that quotes the `quote` bytes of the input
+    /// and points at its `span` bytes.
+    pub const fn new(quote: Range<usize>, span: Range<usize>) -> Self {
+        Self {
+            quote_start: quote.start,
+            quote_end: quote.end,
+            start: span.start,
+            end: span.end,
+        }
+    }
+
+    /// `quote` is a function that shows the byte range of the input that is quoted in the error message.
+    pub const fn quote(&self) -> Range<usize> {
+        self.quote_start..self.quote_end
+    }
+
+    /// `text` is a function that shows the quoted part of the parsed `input`,
+    /// or the whole `input` if it is not the input the error was created for.
+    pub fn text<'a>(&self, input: &'a str) -> &'a str {
+        input.get(self.quote()).unwrap_or(input)
+    }
+
+    /// `span` is a function that shows the byte range of the input that the error points at.
+    pub const fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Moves the ranges by `offset` bytes, used when a part of the input was parsed on its own.
+    pub(crate) const fn shifted(self, offset: usize) -> Self {
+        Self {
+            quote_start: self.quote_start + offset,
+            quote_end: self.quote_end + offset,
+            start: self.start + offset,
+            end: self.end + offset,
+        }
+    }
+
+    /// Writes the end of an error message: the quoted text of `input` if it is known, the span otherwise.
+    pub(crate) fn fmt_with(
+        &self,
+        input: Option<&str>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        match input {
+            Some(input) => write!(f, ": {}", self.text(input)),
+            None => write!(f, " at bytes {}..{}", self.start, self.end),
+        }
+    }
+}
+
+/// A parsing error shown together with the parsed input, in the wording of the error messages
+/// that quote the input, e.g. `invalid token unit: 1.5 UAH`. Created by the `display` methods of the parsing errors.
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorDisplay<'a> {
+    error: ErrorRef<'a>,
+    input: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ErrorRef<'a> {
+    Decimal(&'a crate::DecimalNumberParsingError),
+    Token(&'a UncTokenError),
+    Gas(&'a UncGasError),
+    Percent(&'a PercentError),
+}
+
+impl<'a> ErrorDisplay<'a> {
+    pub(crate) fn decimal(error: &'a crate::DecimalNumberParsingError, input: &'a str) -> Self {
+        Self {
+            error: ErrorRef::Decimal(error),
+            input,
+        }
+    }
+
+    fn span(&self) -> Range<usize> {
+        match self.error {
+            ErrorRef::Decimal(error) => error.span(),
+            ErrorRef::Token(error) => error.span(),
+            ErrorRef::Gas(error) => error.span(),
+            ErrorRef::Percent(error) => error.span(),
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorDisplay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let input = Some(self.input);
+        match self.error {
+            ErrorRef::Decimal(error) => error.fmt_with(input, f),
+            ErrorRef::Token(error) => error.fmt_with(input, f),
+            ErrorRef::Gas(error) => error.fmt_with(input, f),
+            ErrorRef::Percent(error) => error.fmt_with(input, f),
+        }
+    }
+}
+
+/// A parsing error rendered together with the input and a caret line under the part of the input
+/// the error points at, created by the `diagnostic` methods of the parsing errors.
+///
+/// # Examples
+/// ```
+/// use unc_token::UncToken;
+/// let input = "1.2.5 UNC";
+/// let err = input.parse::<UncToken>().unwrap_err();
+/// assert_eq!(
+///     err.diagnostic(input).to_string(),
+///     "invalid tokens amount: invalid number: 1.2.5\n1.2.5 UNC\n   ^"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Diagnostic<'a> {
+    message: ErrorDisplay<'a>,
+}
+
+impl<'a> Diagnostic<'a> {
+    pub(crate) fn new(message: ErrorDisplay<'a>) -> Self {
+        Self { message }
+    }
+}
+
+impl core::fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        let input = self.message.input;
+        let span = self.message.span();
+        writeln!(f, "{}", self.message)?;
+        // Control characters would break the alignment of the carets, so they are shown as spaces.
+        for c in input.chars() {
+            f.write_char(if c.is_control() { ' ' } else { c })?;
+        }
+        f.write_char('\n')?;
+        // The span is measured in bytes, while the carets are aligned by characters.
+        let mut underlined = false;
+        for (index, _) in input.char_indices() {
+            if index >= span.end && underlined {
+                break;
+            }
+            if index < span.start {
+                f.write_char(' ')?;
+            } else if index < span.end {
+                f.write_char('^')?;
+                underlined = true;
+            }
+        }
+        // Empty spans (e.g. a missing unit) and spans at the end of the input get a single caret.
+        if !underlined {
+            f.write_char('^')?;
+        }
+        Ok(())
+    }
+}
+
+/// The kind of a [`ConstParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// The number has invalid characters or misses its whole or fractional part.
+    InvalidNumber,
+    /// The number does not fit into `u128`.
+    LongWhole,
+    /// The number has more fractional digits than its unit allows.
+    LongFractional,
+    /// The number does not fit into `u128` while its whole part does, e.g. `0.5` with 40 decimal places.
+    OutOfRange,
+    /// The unit is missing or unknown.
+    InvalidUnit,
+}
+
+/// Error returned by the `const` parsers: [`parse_decimal_number`](crate::parse_decimal_number),
+/// [`UncToken::parse_const`](crate::UncToken::parse_const) and [`TokenAmount::parse_const`](crate::TokenAmount::parse_const).
+///
+/// This error only holds its kind and the byte ranges of the input;
+/// [`to_decimal_error`](Self::to_decimal_error) and [`to_token_error`](Self::to_token_error)
+/// turn it into the error that `FromStr` returns for the same input.
+///
+/// # Examples
+/// ```
+/// use unc_token::{ParseErrorKind, UncToken};
+/// let input = "1.25 UAH";
+/// let err = UncToken::parse_const(input).unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::InvalidUnit);
+/// assert_eq!(err.span(), 5..8);
+/// assert_eq!(err.to_string(), "invalid token unit at bytes 5..8");
+/// assert_eq!(err.to_token_error().display(input).to_string(), "invalid token unit: 1.25 UAH");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstParseError {
+    kind: ParseErrorKind,
+    fragment: InputFragment,
+}
+
+impl ConstParseError {
+    pub(crate) const fn new(kind: ParseErrorKind, quote: Range<usize>, span: Range<usize>) -> Self {
+        Self {
+            kind,
+            fragment: InputFragment::new(quote, span),
+        }
+    }
+
+    /// `kind` is a function that shows what is wrong with the input.
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// `span` is a function that shows the byte range of the parsed input that caused the error.
+    pub const fn span(&self) -> Range<usize> {
+        self.fragment.span()
+    }
+
+    /// Moves the ranges by `offset` bytes, used when a part of the input was parsed on its own.
+    pub(crate) const fn shifted(self, offset: usize) -> Self {
+        Self {
+            kind: self.kind,
+            fragment: self.fragment.shifted(offset),
+        }
+    }
+
+    /// `to_decimal_error` is a function that creates the error of `parse_decimal_number`'s callers.
+    /// [`ParseErrorKind::InvalidUnit`] becomes `InvalidNumber`.
+    pub const fn to_decimal_error(&self) -> crate::DecimalNumberParsingError {
+        use crate::DecimalNumberParsingError;
+
+        let fragment = self.fragment;
+        match self.kind {
+            ParseErrorKind::LongWhole => DecimalNumberParsingError::LongWhole(fragment),
+            ParseErrorKind::LongFractional => DecimalNumberParsingError::LongFractional(fragment),
+            ParseErrorKind::OutOfRange => DecimalNumberParsingError::OutOfRange(fragment),
+            ParseErrorKind::InvalidNumber | ParseErrorKind::InvalidUnit => {
+                DecimalNumberParsingError::InvalidNumber(fragment)
+            }
+        }
+    }
+
+    /// `to_token_error` is a function that creates the error `UncToken::from_str` returns.
+    pub const fn to_token_error(&self) -> UncTokenError {
+        match self.kind {
+            ParseErrorKind::InvalidUnit => UncTokenError::InvalidTokenUnit(self.fragment),
+            _ => UncTokenError::InvalidTokensAmount(self.to_decimal_error()),
+        }
+    }
+}
+
+impl core::fmt::Display for ConstParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let kind = match self.kind {
+            ParseErrorKind::InvalidNumber => "invalid number",
+            ParseErrorKind::LongWhole => "too long whole part",
+            ParseErrorKind::LongFractional => "too long fractional part",
+            ParseErrorKind::OutOfRange => "number is out of range",
+            ParseErrorKind::InvalidUnit => "invalid token unit",
+        };
+        f.write_str(kind)?;
+        self.fragment.fmt_with(None, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConstParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UncTokenError {
     InvalidTokensAmount(crate::utils::DecimalNumberParsingError),
-    InvalidTokenUnit(String),
+    InvalidTokenUnit(InputFragment),
 }
 
-impl std::fmt::Display for UncTokenError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl UncTokenError {
+    /// `span` is a function that shows the byte range of the parsed input that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            UncTokenError::InvalidTokensAmount(err) => err.span(),
+            UncTokenError::InvalidTokenUnit(unit) => unit.span(),
+        }
+    }
+
+    /// `display` is a function that shows the error with the quoted part of the parsed `input`.
+    pub fn display<'a>(&'a self, input: &'a str) -> ErrorDisplay<'a> {
+        ErrorDisplay {
+            error: ErrorRef::Token(self),
+            input,
+        }
+    }
+
+    /// `diagnostic` is a function that renders the error with the parsed `input` and a caret line under the [`span`](Self::span).
+    pub fn diagnostic<'a>(&'a self, input: &'a str) -> Diagnostic<'a> {
+        Diagnostic::new(self.display(input))
+    }
+
+    pub(crate) fn shifted(self, offset: usize) -> Self {
         match self {
-            UncTokenError::InvalidTokensAmount(err) => write!(f, "invalid tokens amount: {}", err),
-            UncTokenError::InvalidTokenUnit(unit) => write!(f, "invalid token unit: {}", unit),
+            UncTokenError::InvalidTokensAmount(err) => {
+                UncTokenError::InvalidTokensAmount(err.shifted(offset))
+            }
+            UncTokenError::InvalidTokenUnit(unit) => {
+                UncTokenError::InvalidTokenUnit(unit.shifted(offset))
+            }
+        }
+    }
+
+    fn fmt_with(&self, input: Option<&str>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UncTokenError::InvalidTokensAmount(err) => {
+                f.write_str("invalid tokens amount: ")?;
+                err.fmt_with(input, f)
+            }
+            UncTokenError::InvalidTokenUnit(unit) => {
+                f.write_str("invalid token unit")?;
+                unit.fmt_with(input, f)
+            }
         }
     }
 }
 
+impl core::fmt::Display for UncTokenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_with(None, f)
+    }
+}
+
+#[cfg(feature = "std")]
 impl std::error::Error for UncTokenError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -22,18 +353,184 @@ impl std::error::Error for UncTokenError {
     }
 }
 
+/// Error returned when parsing an [`UncGas`](crate::UncGas) amount fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UncGasError {
+    InvalidGasAmount(crate::utils::DecimalNumberParsingError),
+    InvalidGasUnit(InputFragment),
+}
+
+impl UncGasError {
+    /// `span` is a function that shows the byte range of the parsed input that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            UncGasError::InvalidGasAmount(err) => err.span(),
+            UncGasError::InvalidGasUnit(unit) => unit.span(),
+        }
+    }
+
+    /// `display` is a function that shows the error with the quoted part of the parsed `input`.
+    pub fn display<'a>(&'a self, input: &'a str) -> ErrorDisplay<'a> {
+        ErrorDisplay {
+            error: ErrorRef::Gas(self),
+            input,
+        }
+    }
+
+    /// `diagnostic` is a function that renders the error with the parsed `input` and a caret line under the [`span`](Self::span).
+    pub fn diagnostic<'a>(&'a self, input: &'a str) -> Diagnostic<'a> {
+        Diagnostic::new(self.display(input))
+    }
+
+    fn fmt_with(&self, input: Option<&str>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UncGasError::InvalidGasAmount(err) => {
+                f.write_str("invalid gas amount: ")?;
+                err.fmt_with(input, f)
+            }
+            UncGasError::InvalidGasUnit(unit) => {
+                f.write_str("invalid gas unit")?;
+                unit.fmt_with(input, f)
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for UncGasError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_with(None, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UncGasError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UncGasError::InvalidGasAmount(err) => Some(err),
+            UncGasError::InvalidGasUnit(_) => None,
+        }
+    }
+}
+
+/// Error returned when parsing a [`Percent`](crate::Percent) or [`BasisPoints`](crate::BasisPoints) fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentError {
+    InvalidNumber(crate::utils::DecimalNumberParsingError),
+    InvalidUnit(InputFragment),
+    OutOfRange(InputFragment),
+}
+
+impl PercentError {
+    /// `span` is a function that shows the byte range of the parsed input that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            PercentError::InvalidNumber(err) => err.span(),
+            PercentError::InvalidUnit(fragment) | PercentError::OutOfRange(fragment) => {
+                fragment.span()
+            }
+        }
+    }
+
+    /// `display` is a function that shows the error with the quoted part of the parsed `input`.
+    pub fn display<'a>(&'a self, input: &'a str) -> ErrorDisplay<'a> {
+        ErrorDisplay {
+            error: ErrorRef::Percent(self),
+            input,
+        }
+    }
+
+    /// `diagnostic` is a function that renders the error with the parsed `input` and a caret line under the [`span`](Self::span).
+    pub fn diagnostic<'a>(&'a self, input: &'a str) -> Diagnostic<'a> {
+        Diagnostic::new(self.display(input))
+    }
+
+    fn fmt_with(&self, input: Option<&str>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PercentError::InvalidNumber(err) => {
+                f.write_str("invalid percentage: ")?;
+                err.fmt_with(input, f)
+            }
+            PercentError::InvalidUnit(unit) => {
+                f.write_str("invalid percentage unit")?;
+                unit.fmt_with(input, f)
+            }
+            PercentError::OutOfRange(value) => {
+                f.write_str("percentage is out of the 0..=100% range")?;
+                value.fmt_with(input, f)
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for PercentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_with(None, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PercentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PercentError::InvalidNumber(err) => Some(err),
+            PercentError::InvalidUnit(_) | PercentError::OutOfRange(_) => None,
+        }
+    }
+}
+
+/// Error returned when a value cannot be converted into `UncToken`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    /// `UncToken` cannot hold negative amounts.
+    NegativeAmount,
+    /// A floating point value was NaN.
+    NotANumber,
+    /// A floating point value was infinite.
+    Infinite,
+    /// The value is too large to fit into `UncToken`.
+    OutOfRange,
+    /// The amount cannot be represented exactly by the target type.
+    PrecisionLoss,
+}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConversionError::NegativeAmount => write!(f, "negative tokens amount"),
+            ConversionError::NotANumber => write!(f, "tokens amount is not a number"),
+            ConversionError::Infinite => write!(f, "infinite tokens amount"),
+            ConversionError::OutOfRange => write!(f, "tokens amount is out of range"),
+            ConversionError::PrecisionLoss => {
+                write!(
+                    f,
+                    "tokens amount cannot be represented without precision loss"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    /// A fragment that quotes and points at the whole `input`.
+    fn whole(input: &str) -> InputFragment {
+        InputFragment::new(0..input.len(), 0..input.len())
+    }
+
     #[test]
     fn test_unc_token_error_display() {
         assert_eq!(
             format!(
                 "{}",
                 UncTokenError::InvalidTokensAmount(
-                    crate::utils::DecimalNumberParsingError::InvalidNumber("abc".to_owned())
+                    crate::utils::DecimalNumberParsingError::InvalidNumber(whole("abc"))
                 )
+                .display("abc")
             ),
             "invalid tokens amount: invalid number: abc"
         );
@@ -41,8 +538,9 @@ mod test {
             format!(
                 "{}",
                 UncTokenError::InvalidTokensAmount(
-                    crate::utils::DecimalNumberParsingError::LongWhole("999999999999.0".to_owned())
+                    crate::utils::DecimalNumberParsingError::LongWhole(whole("999999999999.0"))
                 )
+                .display("999999999999.0")
             ),
             "invalid tokens amount: too long whole part: 999999999999.0"
         );
@@ -50,16 +548,168 @@ mod test {
             format!(
                 "{}",
                 UncTokenError::InvalidTokensAmount(
-                    crate::utils::DecimalNumberParsingError::LongFractional(
-                        "0.999999999999".to_owned()
-                    )
+                    crate::utils::DecimalNumberParsingError::LongFractional(whole(
+                        "0.999999999999"
+                    ))
                 )
+                .display("0.999999999999")
             ),
             "invalid tokens amount: too long fractional part: 0.999999999999"
         );
         assert_eq!(
-            format!("{}", UncTokenError::InvalidTokenUnit("abc".to_owned())),
+            format!(
+                "{}",
+                UncTokenError::InvalidTokenUnit(whole("abc")).display("abc")
+            ),
             "invalid token unit: abc"
         );
+        assert_eq!(
+            format!(
+                "{}",
+                UncTokenError::InvalidTokenUnit(InputFragment::new(0..5, 2..5))
+            ),
+            "invalid token unit at bytes 2..5"
+        );
+    }
+
+    #[test]
+    fn test_unc_gas_error_display() {
+        assert_eq!(
+            format!(
+                "{}",
+                UncGasError::InvalidGasAmount(
+                    crate::utils::DecimalNumberParsingError::InvalidNumber(whole("abc"))
+                )
+                .display("abc")
+            ),
+            "invalid gas amount: invalid number: abc"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                UncGasError::InvalidGasUnit(whole("Pgas")).display("Pgas")
+            ),
+            "invalid gas unit: Pgas"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                UncGasError::InvalidGasAmount(
+                    crate::utils::DecimalNumberParsingError::InvalidNumber(whole("abc"))
+                )
+            ),
+            "invalid gas amount: invalid number at bytes 0..3"
+        );
+    }
+
+    #[test]
+    fn test_conversion_error_display() {
+        assert_eq!(
+            format!("{}", ConversionError::NegativeAmount),
+            "negative tokens amount"
+        );
+        assert_eq!(
+            format!("{}", ConversionError::NotANumber),
+            "tokens amount is not a number"
+        );
+        assert_eq!(
+            format!("{}", ConversionError::Infinite),
+            "infinite tokens amount"
+        );
+        assert_eq!(
+            format!("{}", ConversionError::OutOfRange),
+            "tokens amount is out of range"
+        );
+        assert_eq!(
+            format!("{}", ConversionError::PrecisionLoss),
+            "tokens amount cannot be represented without precision loss"
+        );
+    }
+
+    #[test]
+    fn test_percent_error_display() {
+        assert_eq!(
+            format!(
+                "{}",
+                PercentError::InvalidNumber(
+                    crate::utils::DecimalNumberParsingError::InvalidNumber(whole("abc"))
+                )
+                .display("abc")
+            ),
+            "invalid percentage: invalid number: abc"
+        );
+        assert_eq!(
+            format!("{}", PercentError::InvalidUnit(whole("2.5")).display("2.5")),
+            "invalid percentage unit: 2.5"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                PercentError::OutOfRange(whole("101%")).display("101%")
+            ),
+            "percentage is out of the 0..=100% range: 101%"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                PercentError::OutOfRange(InputFragment::new(0..4, 0..3))
+            ),
+            "percentage is out of the 0..=100% range at bytes 0..3"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "\t2 \u{b5}UNCS";
+        let err = input.parse::<crate::UncToken>().unwrap_err();
+        assert_eq!(err.span(), 3..9);
+        assert_eq!(
+            err.diagnostic(input).to_string(),
+            "invalid token unit: \t2 \u{b5}UNCS\n 2 \u{b5}UNCS\n   ^^^^^"
+        );
+
+        let input = "12.5";
+        let err = input.parse::<crate::UncToken>().unwrap_err();
+        assert_eq!(
+            err.diagnostic(input).to_string(),
+            "invalid token unit: 12.5\n12.5\n    ^"
+        );
+
+        let input = "1.00001 %";
+        let err = input.parse::<crate::Percent>().unwrap_err();
+        assert_eq!(
+            err.diagnostic(input).to_string(),
+            "invalid percentage: too long fractional part: 00001\n1.00001 %\n  ^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_long_input() {
+        let input = format!("1 {}", "X".repeat(100));
+        let err = input.parse::<crate::UncToken>().unwrap_err();
+        assert_eq!(err.span(), 2..102);
+        assert_eq!(err.to_string(), "invalid token unit at bytes 2..102");
+        assert_eq!(
+            err.display(&input).to_string(),
+            format!("invalid token unit: {}", input)
+        );
+    }
+
+    #[test]
+    fn test_const_parse_error() {
+        let input = "1.2.5 UNC";
+        let err = crate::UncToken::parse_const(input).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(err.span(), 3..4);
+        assert_eq!(err.to_string(), "invalid number at bytes 3..4");
+        assert_eq!(
+            err.to_token_error(),
+            input.parse::<crate::UncToken>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_error_size() {
+        assert!(core::mem::size_of::<Result<crate::UncToken, UncTokenError>>() <= 48);
     }
 }
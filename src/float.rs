@@ -0,0 +1,300 @@
+use crate::{ConversionError, RoundingMode, UncToken};
+
+/// `10^24 = 5^24 * 2^24`, and `5^24 < 2^56`, so dividing or multiplying by `5^24` and shifting
+/// by 24 bits keeps every intermediate value of the conversions below within `u128`.
+const FIVE_POW_24: u128 = 5_u128.pow(24);
+
+/// The number of significant bits of `FIVE_POW_24`.
+const FIVE_POW_24_BITS: u32 = u128::BITS - FIVE_POW_24.leading_zeros();
+
+/// The number of significant bits of an `f64`, including the implicit leading bit.
+const F64_MANTISSA_BITS: u32 = f64::MANTISSA_DIGITS;
+
+/// The result of a conversion between `UncToken` and a floating point number,
+/// together with whether the conversion was exact.
+///
+/// A conversion that is not exact has rounded the value, losing precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatConversion<T> {
+    /// The converted value.
+    pub value: T,
+    /// Whether `value` represents the source value exactly.
+    pub exact: bool,
+}
+
+impl UncToken {
+    /// `to_f64_unc` is a function that converts the amount to a number of UNC as `f64`,
+    /// rounding to the nearest representable value.
+    ///
+    /// Use [`UncToken::to_f64_unc_with_report`] to know whether precision was lost.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// assert_eq!(UncToken::from_milliunc(1_500).to_f64_unc(), 1.5);
+    /// assert_eq!(UncToken::from_attounc(1).to_f64_unc(), 1e-24);
+    /// ```
+    pub fn to_f64_unc(self) -> f64 {
+        self.to_f64_unc_with_report().value
+    }
+
+    /// `to_f64_unc_with_report` is a function that converts the amount to a number of UNC as `f64`,
+    /// rounding to the nearest representable value, and reports whether the result is exact.
+    ///
+    /// Unlike `as_attounc() as f64 / 1e24`, the result is correctly rounded: it is the `f64`
+    /// closest to the amount.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// let half = UncToken::from_milliunc(500).to_f64_unc_with_report();
+    /// assert_eq!((half.value, half.exact), (0.5, true));
+    /// let tenth = UncToken::from_milliunc(100).to_f64_unc_with_report();
+    /// assert_eq!((tenth.value, tenth.exact), (0.1, false));
+    /// ```
+    pub fn to_f64_unc_with_report(self) -> FloatConversion<f64> {
+        let attounc = self.as_attounc();
+        if attounc == 0 {
+            return FloatConversion {
+                value: 0.0,
+                exact: true,
+            };
+        }
+        // Scale the amount by `2^shift` so that `attounc * 2^shift / 5^24` has 63 or 64 bits.
+        let attounc_bits = u128::BITS - attounc.leading_zeros();
+        let shift = 63 + FIVE_POW_24_BITS as i32 - attounc_bits as i32;
+        let (quotient, inexact_division) = if shift >= 0 {
+            let scaled = attounc << shift;
+            (scaled / FIVE_POW_24, scaled % FIVE_POW_24 != 0)
+        } else {
+            let truncated = attounc >> -shift;
+            let lost_bits = attounc & ((1 << -shift) - 1);
+            (
+                truncated / FIVE_POW_24,
+                lost_bits != 0 || truncated % FIVE_POW_24 != 0,
+            )
+        };
+
+        let extra_bits = u128::BITS - quotient.leading_zeros() - F64_MANTISSA_BITS;
+        let mantissa = quotient >> extra_bits;
+        // The bits dropped from the quotient, followed by a sticky bit for the division remainder,
+        // so that values above a tie are never mistaken for a tie.
+        let remainder = ((quotient & ((1 << extra_bits) - 1)) << 1) | inexact_division as u128;
+        let mantissa =
+            if RoundingMode::HalfEven.rounds_up(mantissa, remainder, 1 << (extra_bits + 1)) {
+                mantissa + 1
+            } else {
+                mantissa
+            };
+
+        FloatConversion {
+            value: mantissa as f64 * pow2(extra_bits as i32 - shift - 24),
+            exact: remainder == 0,
+        }
+    }
+
+    /// `try_from_f64_unc` is a function that converts a number of UNC given as `f64` into `UncToken`,
+    /// rounding the fractions of an atto-unc according to `rounding` and reporting whether the result is exact.
+    ///
+    /// Every finite `f64` is converted from its exact value, so `0.1` is converted from
+    /// `0.1000000000000000055511151231257827...` rather than from the decimal number `0.1`.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::NotANumber`] for NaN, [`ConversionError::Infinite`] for infinities,
+    /// [`ConversionError::NegativeAmount`] for negative values and [`ConversionError::OutOfRange`]
+    /// for values that do not fit into `UncToken`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{ConversionError, RoundingMode, UncToken};
+    /// let amount = UncToken::try_from_f64_unc(1.5, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!((amount.value, amount.exact), (UncToken::from_milliunc(1_500), true));
+    ///
+    /// let amount = UncToken::try_from_f64_unc(0.1, RoundingMode::Down).unwrap();
+    /// assert_eq!(amount.value, UncToken::from_attounc(100_000_000_000_000_005_551_115));
+    /// assert!(!amount.exact);
+    ///
+    /// assert_eq!(UncToken::try_from_f64_unc(f64::NAN, RoundingMode::Down), Err(ConversionError::NotANumber));
+    /// assert_eq!(UncToken::try_from_f64_unc(-1.0, RoundingMode::Down), Err(ConversionError::NegativeAmount));
+    /// assert_eq!(UncToken::try_from_f64_unc(1e15, RoundingMode::Down), Err(ConversionError::OutOfRange));
+    /// ```
+    pub fn try_from_f64_unc(
+        value: f64,
+        rounding: RoundingMode,
+    ) -> Result<FloatConversion<UncToken>, ConversionError> {
+        if value.is_nan() {
+            return Err(ConversionError::NotANumber);
+        }
+        if value.is_infinite() {
+            return Err(ConversionError::Infinite);
+        }
+        if value < 0.0 {
+            return Err(ConversionError::NegativeAmount);
+        }
+
+        // `value = mantissa * 2^exponent`, so the amount is `mantissa * 5^24 * 2^(exponent + 24)` atto-unc.
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as u128;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased_exponent - 1075)
+        };
+        // At most 2^53 * 2^56, so it cannot overflow.
+        let scaled = mantissa * FIVE_POW_24;
+        let shift = exponent + 24;
+
+        if shift >= 0 {
+            if scaled != 0 && (shift >= u128::BITS as i32 || scaled.leading_zeros() < shift as u32)
+            {
+                return Err(ConversionError::OutOfRange);
+            }
+            return Ok(FloatConversion {
+                value: UncToken::from_attounc(scaled << shift),
+                exact: true,
+            });
+        }
+
+        let shift = -shift as u32;
+        let (quotient, remainder, divisor) = if shift < u128::BITS {
+            (scaled >> shift, scaled & ((1 << shift) - 1), 1 << shift)
+        } else {
+            // `scaled < 2^109`, so the fraction is below a half for any larger divisor than `2^128`;
+            // `2^127` stands in for it, as it compares to `scaled` the same way.
+            (0, scaled, 1 << (u128::BITS - 1))
+        };
+        // `quotient < 2^109`, so incrementing it cannot overflow.
+        let attounc = if rounding.rounds_up(quotient, remainder, divisor) {
+            quotient + 1
+        } else {
+            quotient
+        };
+        Ok(FloatConversion {
+            value: UncToken::from_attounc(attounc),
+            exact: remainder == 0,
+        })
+    }
+}
+
+/// Computes `2^exponent` for exponents within the range of normal `f64` values.
+fn pow2(exponent: i32) -> f64 {
+    debug_assert!((f64::MIN_EXP - 1..f64::MAX_EXP).contains(&exponent));
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+#[cfg(test)]
+mod test {
+    use super::FIVE_POW_24;
+    use crate::{ConversionError, RoundingMode, UncToken};
+
+    #[test]
+    fn five_pow_24() {
+        assert_eq!(FIVE_POW_24 << 24, 10u128.pow(24));
+    }
+
+    #[test]
+    fn to_f64_unc() {
+        for (attounc, value, exact) in [
+            (0, 0.0, true),
+            (10u128.pow(24), 1.0, true),
+            (10u128.pow(24) / 4, 0.25, true),
+            (10u128.pow(23), 0.1, false),
+            (1, 1e-24, false),
+            (12_345 * 10u128.pow(21), 12.345, false),
+            (u128::MAX, 340_282_366_920_938.44, false),
+            (1 << 100, 1.267_650_600_228_229_3e6, false),
+        ] {
+            let conversion = UncToken::from_attounc(attounc).to_f64_unc_with_report();
+            assert_eq!(conversion.value, value, "{}", attounc);
+            assert_eq!(conversion.exact, exact, "{}", attounc);
+        }
+        // 2^29 + 2^-24 UNC is a tie between 2^29 and 2^29 + 2^-23, rounded to the even mantissa.
+        let tie = UncToken::from_unc(1 << 29).saturating_add(UncToken::from_attounc(FIVE_POW_24));
+        assert_eq!(tie.to_f64_unc(), (1 << 29) as f64);
+        // Anything above the tie rounds up.
+        let above_tie = tie.saturating_add(UncToken::from_attounc(1));
+        assert_eq!(
+            above_tie.to_f64_unc(),
+            (1 << 29) as f64 + 1.0 / (1 << 23) as f64
+        );
+        // 2^29 + 3 * 2^-24 UNC is a tie between an odd and an even mantissa, rounded up to the even one.
+        let tie = tie.saturating_add(UncToken::from_attounc(2 * FIVE_POW_24));
+        assert_eq!(tie.to_f64_unc(), (1 << 29) as f64 + 1.0 / (1 << 22) as f64);
+    }
+
+    #[test]
+    fn try_from_f64_unc() {
+        let convert = |value: f64, rounding| {
+            UncToken::try_from_f64_unc(value, rounding).map(|c| (c.value.as_attounc(), c.exact))
+        };
+        assert_eq!(convert(0.0, RoundingMode::Up), Ok((0, true)));
+        assert_eq!(convert(-0.0, RoundingMode::Up), Ok((0, true)));
+        assert_eq!(
+            convert(2.5, RoundingMode::Up),
+            Ok((25 * 10u128.pow(23), true))
+        );
+        assert_eq!(convert(1e-24, RoundingMode::Down), Ok((0, false)));
+        assert_eq!(convert(1e-24, RoundingMode::HalfEven), Ok((1, false)));
+        assert_eq!(convert(f64::MIN_POSITIVE, RoundingMode::Up), Ok((1, false)));
+        assert_eq!(
+            convert(f64::MIN_POSITIVE, RoundingMode::HalfUp),
+            Ok((0, false))
+        );
+        assert_eq!(convert(5e-324, RoundingMode::Up), Ok((1, false)));
+        assert_eq!(
+            convert(0.1, RoundingMode::Up),
+            Ok((100_000_000_000_000_005_551_116, false))
+        );
+        // 2^-25 UNC is exactly 29802322387695312.5 atto-unc.
+        let tie = 1.0 / (1u64 << 25) as f64;
+        assert_eq!(
+            convert(tie, RoundingMode::HalfEven),
+            Ok((29_802_322_387_695_312, false))
+        );
+        assert_eq!(
+            convert(tie, RoundingMode::HalfUp),
+            Ok((29_802_322_387_695_313, false))
+        );
+
+        // The nearest `f64` to `u128::MAX` atto-unc is below it, the next one is above.
+        let max = UncToken::from_attounc(u128::MAX).to_f64_unc();
+        assert_eq!(
+            convert(max, RoundingMode::Up).map(|(_, exact)| exact),
+            Ok(true)
+        );
+        let above_max = f64::from_bits(max.to_bits() + 1);
+        assert_eq!(
+            convert(above_max, RoundingMode::Down),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            convert(f64::MAX, RoundingMode::Down),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            convert(f64::INFINITY, RoundingMode::Down),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(
+            convert(f64::NEG_INFINITY, RoundingMode::Down),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(
+            convert(f64::NAN, RoundingMode::Down),
+            Err(ConversionError::NotANumber)
+        );
+        assert_eq!(
+            convert(-1e-300, RoundingMode::Down),
+            Err(ConversionError::NegativeAmount)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in [0.1, 1.0, 12.345, 1e-6, 123_456_789.123_456_78, 3e14] {
+            let token = UncToken::try_from_f64_unc(value, RoundingMode::HalfEven).unwrap();
+            assert_eq!(token.value.to_f64_unc(), value);
+        }
+    }
+}
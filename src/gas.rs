@@ -0,0 +1,250 @@
+use crate::UncToken;
+
+pub(crate) const ONE_TERA_GAS: u64 = 10_u64.pow(12);
+pub(crate) const ONE_GIGA_GAS: u64 = 10_u64.pow(9);
+
+/// An amount of gas, e.g. `30 Tgas` attached to a function call.
+///
+/// # Examples
+/// ```
+/// use unc_token::{GasPrice, UncGas, UncToken};
+///
+/// let gas: UncGas = "30 Tgas".parse().unwrap();
+/// assert_eq!(gas, UncGas::from_ggas(30_000));
+/// assert_eq!(gas.to_string(), "30.00 Tgas");
+///
+/// let price = GasPrice::from_attounc(100_000_000);
+/// assert_eq!(gas * price, UncToken::from_milliunc(3));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct UncGas {
+    inner: u64,
+}
+
+impl UncGas {
+    /// `from_gas` is a function that takes value by a number of gas units.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncGas;
+    /// assert_eq!(UncGas::from_gas(10u64.pow(9)), UncGas::from_ggas(1))
+    /// ```
+    pub const fn from_gas(inner: u64) -> Self {
+        Self { inner }
+    }
+
+    /// `from_ggas` is a function that takes value by a number of giga-gas and converts it to an equivalent to the gas units.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncGas;
+    /// assert_eq!(UncGas::from_ggas(1), UncGas::from_gas(10u64.pow(9)))
+    /// ```
+    pub const fn from_ggas(inner: u64) -> Self {
+        Self {
+            inner: inner * ONE_GIGA_GAS,
+        }
+    }
+
+    /// `from_tgas` is a function that takes value by a number of tera-gas and converts it to an equivalent to the gas units.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncGas;
+    /// assert_eq!(UncGas::from_tgas(1), UncGas::from_gas(10u64.pow(12)))
+    /// ```
+    pub const fn from_tgas(inner: u64) -> Self {
+        Self {
+            inner: inner * ONE_TERA_GAS,
+        }
+    }
+
+    /// `as_gas` is a function that shows a number of gas units.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncGas;
+    /// assert_eq!(UncGas::from_gas(10).as_gas(), 10)
+    /// ```
+    pub const fn as_gas(&self) -> u64 {
+        self.inner
+    }
+
+    /// `as_ggas` is a function that converts number of gas units to an equivalent to the giga-gas.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncGas;
+    /// assert_eq!(UncGas::from_gas(10u64.pow(9)).as_ggas(), 1)
+    /// ```
+    pub const fn as_ggas(&self) -> u64 {
+        self.inner / ONE_GIGA_GAS
+    }
+
+    /// `as_tgas` is a function that converts number of gas units to an equivalent to the tera-gas.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncGas;
+    /// assert_eq!(UncGas::from_gas(10u64.pow(12)).as_tgas(), 1)
+    /// ```
+    pub const fn as_tgas(&self) -> u64 {
+        self.inner / ONE_TERA_GAS
+    }
+
+    /// `is_zero` is a boolian function that checks `UncGas`
+    /// if a `UncGas` inner is zero, returns true.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncGas;
+    /// assert_eq!(UncGas::from_gas(0).is_zero(), true)
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.inner == 0
+    }
+
+    /// Checked cost of the gas. Computes self * price, returning None if the cost does not fit into `UncToken`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{GasPrice, UncGas, UncToken};
+    /// let price = GasPrice::from_attounc(100_000_000);
+    /// assert_eq!(UncGas::from_tgas(300).checked_cost(price), Some(UncToken::from_milliunc(30)));
+    /// assert_eq!(UncGas::from_gas(u64::MAX).checked_cost(GasPrice::from_attounc(u128::MAX)), None);
+    /// ```
+    pub const fn checked_cost(self, price: GasPrice) -> Option<UncToken> {
+        match (self.inner as u128).checked_mul(price.as_attounc()) {
+            Some(attounc) => Some(UncToken::from_attounc(attounc)),
+            None => None,
+        }
+    }
+}
+
+impl_checked_add_sub!([] UncGas, u64, from_gas, as_gas, "UncGas");
+impl_checked_mul_div!([] UncGas, u64, from_gas, as_gas, "UncGas");
+
+/// The price of one unit of gas in atto-unc.
+///
+/// Multiplying [`UncGas`] by a `GasPrice` gives the cost as an [`UncToken`] amount,
+/// and [`GasPrice::gas_for`] computes how much gas a balance can buy.
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct GasPrice {
+    attounc_per_gas: u128,
+}
+
+impl GasPrice {
+    /// `from_attounc` is a function that takes value by a number of atto-unc per unit of gas.
+    /// # Examples
+    /// ```
+    /// use unc_token::GasPrice;
+    /// assert_eq!(GasPrice::from_attounc(100_000_000).as_attounc(), 100_000_000)
+    /// ```
+    pub const fn from_attounc(attounc_per_gas: u128) -> Self {
+        Self { attounc_per_gas }
+    }
+
+    /// `as_attounc` is a function that shows a number of atto-unc per unit of gas.
+    pub const fn as_attounc(&self) -> u128 {
+        self.attounc_per_gas
+    }
+
+    /// `is_zero` is a boolian function that checks if the gas is free.
+    pub const fn is_zero(&self) -> bool {
+        self.attounc_per_gas == 0
+    }
+
+    /// Computes how much gas `balance` can buy at this price, rounding down.
+    ///
+    /// Returns None if the price is zero or the balance could buy more than `u64::MAX` units of gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{GasPrice, UncGas, UncToken};
+    /// let price = GasPrice::from_attounc(100_000_000);
+    /// assert_eq!(price.gas_for(UncToken::from_milliunc(30)), Some(UncGas::from_tgas(300)));
+    /// assert_eq!(price.gas_for(UncToken::from_attounc(99_999_999)), Some(UncGas::from_gas(0)));
+    /// assert_eq!(GasPrice::from_attounc(0).gas_for(UncToken::from_unc(1)), None);
+    /// assert_eq!(GasPrice::from_attounc(1).gas_for(UncToken::from_unc(1)), None);
+    /// ```
+    pub const fn gas_for(self, balance: UncToken) -> Option<UncGas> {
+        match balance.as_attounc().checked_div(self.attounc_per_gas) {
+            Some(gas) if gas <= u64::MAX as u128 => Some(UncGas::from_gas(gas as u64)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasPrice, UncGas, UncToken};
+
+    #[test]
+    fn gas_units() {
+        assert_eq!(UncGas::from_tgas(1), UncGas::from_ggas(1000));
+        assert_eq!(UncGas::from_tgas(1).as_gas(), 10u64.pow(12));
+        assert_eq!(UncGas::from_gas(1_999_999_999_999).as_tgas(), 1);
+        assert_eq!(UncGas::from_gas(1_999_999_999).as_ggas(), 1);
+        assert!(UncGas::default().is_zero());
+    }
+
+    #[test]
+    fn checked_and_saturating_gas() {
+        let max = UncGas::from_gas(u64::MAX);
+        assert_eq!(max.checked_add(UncGas::from_gas(1)), None);
+        assert_eq!(UncGas::from_gas(0).checked_sub(UncGas::from_gas(1)), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(max.checked_div(0), None);
+        assert_eq!(max.saturating_add(UncGas::from_gas(1)), max);
+        assert_eq!(
+            UncGas::from_gas(0).saturating_sub(UncGas::from_gas(1)),
+            UncGas::from_gas(0)
+        );
+        assert_eq!(max.saturating_mul(2), max);
+        assert_eq!(max.saturating_div(0), UncGas::from_gas(0));
+    }
+
+    #[test]
+    fn gas_cost() {
+        let price = GasPrice::from_attounc(100_000_000);
+        assert_eq!(
+            UncGas::from_tgas(1).checked_cost(price),
+            Some(UncToken::from_microunc(100))
+        );
+        assert_eq!(
+            UncGas::from_gas(u64::MAX)
+                .checked_cost(GasPrice::from_attounc(u128::MAX / u64::MAX as u128)),
+            Some(UncToken::from_attounc(u128::MAX))
+        );
+        assert_eq!(
+            UncGas::from_gas(2).checked_cost(GasPrice::from_attounc(u128::MAX / 2 + 1)),
+            None
+        );
+        assert_eq!(
+            UncGas::from_gas(0).checked_cost(GasPrice::from_attounc(u128::MAX)),
+            Some(UncToken::from_attounc(0))
+        );
+    }
+
+    #[test]
+    fn gas_for_balance() {
+        let price = GasPrice::from_attounc(100_000_000);
+        let gas = UncGas::from_tgas(300);
+        assert_eq!(price.gas_for(gas.checked_cost(price).unwrap()), Some(gas));
+        assert_eq!(
+            GasPrice::from_attounc(1).gas_for(UncToken::from_attounc(u64::MAX.into())),
+            Some(UncGas::from_gas(u64::MAX))
+        );
+        assert_eq!(
+            GasPrice::from_attounc(1).gas_for(UncToken::from_attounc(u64::MAX as u128 + 1)),
+            None
+        );
+        assert_eq!(GasPrice::default().gas_for(UncToken::from_unc(1)), None);
+        assert!(GasPrice::default().is_zero());
+    }
+}
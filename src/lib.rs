@@ -5,34 +5,139 @@
 //!
 //! # Examples
 //! ```
-//! use unc_token::UncToken;
+//! use unc_token::{unc, UncToken};
 //!
 //! let one_unc = UncToken::from_attounc(10_u128.pow(24));
 //! assert_eq!(one_unc, UncToken::from_unc(1));
 //! assert_eq!(one_unc, UncToken::from_milliunc(1000));
+//! assert_eq!(one_unc, unc!("1 UNC"));
 //! ```
 //!
+//! Amounts of other fungible tokens are represented by [`TokenAmount`], which is generic over
+//! the number of decimals of the token, so amounts of different tokens can't be mixed up.
+//! When the decimals and the symbol of a token are only known at runtime, [`TokenMetadata`]
+//! formats and parses its amounts.
+//!
+//! Gas is represented by [`UncGas`], and its cost at a [`GasPrice`] is an `UncToken` amount.
+//! Likewise, [`StorageUsage`] converts to the amount locked for storage at a [`StoragePrice`].
+//!
+//! For analytics, [`UncToken::to_f64_unc`] and [`UncToken::try_from_f64_unc`] convert amounts to and from
+//! `f64` numbers of UNC, and report whether precision was lost in a [`FloatConversion`].
+//!
 //! # Crate features
 //!
+//! * **std** (default) -
+//!   Implements `std::error::Error` for the error types. Implies **alloc**.
+//!
+//! * **alloc** (enabled by **std**) -
+//!   Enables the APIs that return or hold heap-allocated values, such as `UncToken::split_by_weights`
+//!   and `TokenMetadata`.
+//!   Without **std** and **alloc** the crate is `no_std` and only depends on `core`.
+//!   Parsing errors never allocate with any features: they hold byte ranges of the input,
+//!   which their `display` and `diagnostic` methods take to quote it.
+//!
 //! * **borsh** (optional) -
-//!   When enabled allows `UncToken` to serialized and deserialized by `borsh`.
+//!   When enabled allows `UncToken` and the other amount types to serialized and deserialized by `borsh`. Implies **alloc**.
 //!
 //! * **serde** (optional) -
 //!   When enabled allows `UncToken` to serialized and deserialized by `serde`.
+//!   The [`serde`](crate::serde) module provides alternative representations for `#[serde(with = "...")]`.
 //!
 //! * **schemars** (optional) -
-//!  Implements `schemars::JsonSchema` for `UncToken`.
+//!   Implements `schemars::JsonSchema` for `UncToken`. Implies **std**.
+//!
+//! * **rust_decimal** (optional) -
+//!   Implements `TryFrom<rust_decimal::Decimal>` for `UncToken` and the reverse, in UNC units with a scale of 24.
+//!   Implies **alloc**.
+//!
+//! * **bigdecimal** (optional) -
+//!   Implements `From<UncToken>` for `bigdecimal::BigDecimal`, in UNC units with a scale of 24, and the reverse `TryFrom`.
+//!   Implies **alloc**.
+//!
+//! * **num-traits** (optional) -
+//!   Implements the `num_traits` traits that apply to amounts, such as `Zero`, `Bounded`, `CheckedAdd`
+//!   and `SaturatingAdd`, for `UncToken`, `TokenAmount`, `UncGas` and `StorageUsage`.
+//!   `CheckedMul`, `CheckedDiv`, `SaturatingMul`, `One` and `Num` are not implemented: they require
+//!   `Mul<Self>`/`Div<Self>`, and the product of two amounts is not an amount. Generic code that needs
+//!   them can use the inherent `checked_mul`, `checked_div` and `checked_mul_div`, which take a plain integer.
+//!
+//! * **sqlx-postgres**, **sqlx-sqlite** (optional) -
+//!   Implement `sqlx::Type`, `Encode` and `Decode` for `UncToken`. PostgreSQL stores the amount as a `NUMERIC`
+//!   number of atto-unc (e.g. in a `NUMERIC(39, 0)` column) and SQLite as `TEXT`. Imply **std**.
+//!
+//! * **diesel-postgres**, **diesel-sqlite** (optional) -
+//!   Implement `diesel` `ToSql` and `FromSql` for `UncToken`, for the `Numeric` type on PostgreSQL
+//!   and the `Text` type on SQLite. Imply **std**.
+//!
+//! * **postgres-types** (optional) -
+//!   Implements `postgres_types::ToSql` and `FromSql` for `UncToken` as a `NUMERIC` number of atto-unc.
+//!   Implies **std**.
+//!
+//! * **clap** (optional) -
+//!   Makes `UncToken` arguments of `clap` parse with [`UncTokenValueParser`](crate::clap::UncTokenValueParser),
+//!   which suggests units on typos and supports bounds. Implies **std** and enables the `derive` feature of `clap`.
 //!
 //! * **interactive-clap** (optional) -
-//!  Implements `interactive_clap::ToCli` for `UncToken`.
+//!   Implements `interactive_clap::ToCli` for `UncToken`. Implies **std**.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod macros;
+
+#[macro_use]
+mod arithmetic;
+
+#[cfg(feature = "clap")]
+pub mod clap;
+
 mod error;
 
+mod float;
+
+mod gas;
+
+mod math;
+
+mod percent;
+
+mod rounding;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+mod signed;
+
+#[cfg(feature = "alloc")]
+mod split;
+
+mod storage;
+
+mod token_amount;
+
+#[cfg(feature = "alloc")]
+mod token_metadata;
+
 mod utils;
 
 mod trait_impls;
 
-pub use self::error::UncTokenError;
-pub use self::utils::DecimalNumberParsingError;
+pub use self::error::{
+    ConstParseError, ConversionError, Diagnostic, ErrorDisplay, InputFragment, ParseErrorKind,
+    PercentError, UncGasError, UncTokenError,
+};
+pub use self::float::FloatConversion;
+pub use self::gas::{GasPrice, UncGas};
+pub use self::percent::{BasisPoints, Percent};
+pub use self::rounding::RoundingMode;
+pub use self::signed::SignedUncToken;
+pub use self::storage::{StoragePrice, StorageUsage};
+pub use self::token_amount::TokenAmount;
+#[cfg(feature = "alloc")]
+pub use self::token_metadata::{DynTokenAmount, TokenMetadata};
+pub use self::trait_impls::display::UncTokenDisplay;
+pub use self::utils::{parse_decimal_number, DecimalNumberParsingError};
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
 #[cfg_attr(
@@ -40,15 +145,32 @@ pub use self::utils::DecimalNumberParsingError;
     derive(borsh::BorshDeserialize, borsh::BorshSerialize)
 )]
 #[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[cfg_attr(
+    any(feature = "diesel-postgres", feature = "diesel-sqlite"),
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel-postgres", diesel(sql_type = diesel::sql_types::Numeric))]
+#[cfg_attr(feature = "diesel-sqlite", diesel(sql_type = diesel::sql_types::Text))]
 #[repr(transparent)]
 pub struct UncToken {
     inner: u128,
 }
 
+const ONE_KILOUNC: u128 = 10_u128.pow(27);
 const ONE_UNC: u128 = 10_u128.pow(24);
 const ONE_MILLIUNC: u128 = 10_u128.pow(21);
+const ONE_MICROUNC: u128 = 10_u128.pow(18);
+const ONE_NANOUNC: u128 = 10_u128.pow(15);
+const ONE_PICOUNC: u128 = 10_u128.pow(12);
+const ONE_FEMTOUNC: u128 = 10_u128.pow(9);
 
 impl UncToken {
+    /// The smallest amount: zero.
+    pub const MIN: UncToken = UncToken::from_attounc(0);
+
+    /// The largest amount: `u128::MAX` atto-unc.
+    pub const MAX: UncToken = UncToken::from_attounc(u128::MAX);
+
     /// `from_attounc` is a function that takes value by a number of atto-unc.
     /// # Examples
     /// ```
@@ -59,6 +181,54 @@ impl UncToken {
         Self { inner }
     }
 
+    /// `from_femtounc` is a function that takes value by a number of femto-unc and converts it to an equivalent to the atto-unc.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// assert_eq!(UncToken::from_femtounc(1), UncToken::from_attounc(10u128.pow(9)))
+    /// ```
+    pub const fn from_femtounc(inner: u128) -> Self {
+        Self {
+            inner: inner * ONE_FEMTOUNC,
+        }
+    }
+
+    /// `from_picounc` is a function that takes value by a number of pico-unc and converts it to an equivalent to the atto-unc.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// assert_eq!(UncToken::from_picounc(1), UncToken::from_attounc(10u128.pow(12)))
+    /// ```
+    pub const fn from_picounc(inner: u128) -> Self {
+        Self {
+            inner: inner * ONE_PICOUNC,
+        }
+    }
+
+    /// `from_nanounc` is a function that takes value by a number of nano-unc and converts it to an equivalent to the atto-unc.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// assert_eq!(UncToken::from_nanounc(1), UncToken::from_attounc(10u128.pow(15)))
+    /// ```
+    pub const fn from_nanounc(inner: u128) -> Self {
+        Self {
+            inner: inner * ONE_NANOUNC,
+        }
+    }
+
+    /// `from_microunc` is a function that takes value by a number of micro-unc and converts it to an equivalent to the atto-unc.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// assert_eq!(UncToken::from_microunc(1), UncToken::from_attounc(10u128.pow(18)))
+    /// ```
+    pub const fn from_microunc(inner: u128) -> Self {
+        Self {
+            inner: inner * ONE_MICROUNC,
+        }
+    }
+
     /// `from_milliunc` is a function that takes value by a number of mili-unc and converts it to an equivalent to the atto-unc.
     /// # Examples
     /// ```
@@ -83,165 +253,158 @@ impl UncToken {
         }
     }
 
-    /// `as_unc` is a function that converts number of atto-unc to an equivalent to the unc.
+    /// `from_kilounc` is a function that takes value by a number of kilo-unc and converts it to an equivalent to the atto-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(10u128.pow(24)).as_unc(), 1)
+    /// assert_eq!(UncToken::from_kilounc(1), UncToken::from_unc(1000))
     /// ```
-    pub const fn as_unc(&self) -> u128 {
-        self.inner / ONE_UNC
+    pub const fn from_kilounc(inner: u128) -> Self {
+        Self {
+            inner: inner * ONE_KILOUNC,
+        }
     }
 
-    /// `as_milliunc` is a function that converts number of atto-unc to an equivalent to the mili-unc.
+    /// `as_kilounc` is a function that converts number of atto-unc to an equivalent to the kilo-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(10u128.pow(21)).as_milliunc(), 1)
+    /// assert_eq!(UncToken::from_unc(1000).as_kilounc(), 1)
     /// ```
-    pub const fn as_milliunc(&self) -> u128 {
-        self.inner / ONE_MILLIUNC
+    pub const fn as_kilounc(&self) -> u128 {
+        self.inner / ONE_KILOUNC
     }
 
-    /// `as_attounc` is a function that shows a number of atto-unc.
+    /// `as_unc` is a function that converts number of atto-unc to an equivalent to the unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(10).as_attounc(), 10)
+    /// assert_eq!(UncToken::from_attounc(10u128.pow(24)).as_unc(), 1)
     /// ```
-    pub const fn as_attounc(&self) -> u128 {
-        self.inner
+    pub const fn as_unc(&self) -> u128 {
+        self.inner / ONE_UNC
     }
 
-    /// `is_zero` is a boolian function that checks `UncToken`
-    /// if a `UncToken` inner is zero, returns true.
+    /// `as_unc_rounded` is a function that converts number of atto-unc to an equivalent to the unc,
+    /// rounding the result according to the given [`RoundingMode`].
     /// # Examples
     /// ```
-    /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(0).is_zero(), true)
+    /// use unc_token::{RoundingMode, UncToken};
+    /// let balance = UncToken::from_attounc(10u128.pow(24) - 1);
+    /// assert_eq!(balance.as_unc(), 0);
+    /// assert_eq!(balance.as_unc_rounded(RoundingMode::Up), 1);
+    /// assert_eq!(balance.as_unc_rounded(RoundingMode::Down), 0);
     /// ```
-    pub const fn is_zero(&self) -> bool {
-        self.inner == 0
+    pub const fn as_unc_rounded(&self, rounding: RoundingMode) -> u128 {
+        rounding.div(self.inner, ONE_UNC)
     }
 
-    /// Checked integer addition. Computes self + rhs, returning None if overflow occurred.
-    ///
+    /// `as_milliunc` is a function that converts number of atto-unc to an equivalent to the mili-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// use std::u128;
-    /// assert_eq!(UncToken::from_attounc(u128::MAX -2).checked_add(UncToken::from_attounc(2)), Some(UncToken::from_attounc(u128::MAX)));
-    /// assert_eq!(UncToken::from_attounc(u128::MAX -2).checked_add(UncToken::from_attounc(3)), None);
-    /// ```
-    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
-        if let Some(unc) = self.as_attounc().checked_add(rhs.as_attounc()) {
-            Some(Self::from_attounc(unc))
-        } else {
-            None
-        }
+    /// assert_eq!(UncToken::from_attounc(10u128.pow(21)).as_milliunc(), 1)
+    /// ```
+    pub const fn as_milliunc(&self) -> u128 {
+        self.inner / ONE_MILLIUNC
     }
 
-    /// Checked integer subtraction. Computes self - rhs, returning None if overflow occurred.
-    ///
+    /// `as_milliunc_rounded` is a function that converts number of atto-unc to an equivalent to the mili-unc,
+    /// rounding the result according to the given [`RoundingMode`].
     /// # Examples
     /// ```
-    /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(2).checked_sub(UncToken::from_attounc(2)), Some(UncToken::from_attounc(0)));
-    /// assert_eq!(UncToken::from_attounc(2).checked_sub(UncToken::from_attounc(3)), None);
+    /// use unc_token::{RoundingMode, UncToken};
+    /// let balance = UncToken::from_attounc(10u128.pow(21) / 2);
+    /// assert_eq!(balance.as_milliunc_rounded(RoundingMode::HalfUp), 1);
+    /// assert_eq!(balance.as_milliunc_rounded(RoundingMode::HalfEven), 0);
     /// ```
-    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
-        if let Some(unc) = self.as_attounc().checked_sub(rhs.as_attounc()) {
-            Some(Self::from_attounc(unc))
-        } else {
-            None
-        }
+    pub const fn as_milliunc_rounded(&self, rounding: RoundingMode) -> u128 {
+        rounding.div(self.inner, ONE_MILLIUNC)
     }
 
-    /// Checked integer multiplication. Computes self * rhs, returning None if overflow occurred.
-    ///
+    /// `as_microunc` is a function that converts number of atto-unc to an equivalent to the micro-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// use std::u128;
-    /// assert_eq!(UncToken::from_attounc(2).checked_mul(2), Some(UncToken::from_attounc(4)));
-    /// assert_eq!(UncToken::from_attounc(u128::MAX).checked_mul(2), None)
-    pub const fn checked_mul(self, rhs: u128) -> Option<Self> {
-        if let Some(unc) = self.as_attounc().checked_mul(rhs) {
-            Some(Self::from_attounc(unc))
-        } else {
-            None
-        }
+    /// assert_eq!(UncToken::from_attounc(10u128.pow(18)).as_microunc(), 1)
+    /// ```
+    pub const fn as_microunc(&self) -> u128 {
+        self.inner / ONE_MICROUNC
     }
 
-    /// Checked integer division. Computes self / rhs, returning None if rhs == 0.
-    ///
+    /// `as_nanounc` is a function that converts number of atto-unc to an equivalent to the nano-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(10).checked_div(2), Some(UncToken::from_attounc(5)));
-    /// assert_eq!(UncToken::from_attounc(2).checked_div(0), None);
+    /// assert_eq!(UncToken::from_attounc(10u128.pow(15)).as_nanounc(), 1)
     /// ```
-    pub const fn checked_div(self, rhs: u128) -> Option<Self> {
-        if let Some(unc) = self.as_attounc().checked_div(rhs) {
-            Some(Self::from_attounc(unc))
-        } else {
-            None
-        }
+    pub const fn as_nanounc(&self) -> u128 {
+        self.inner / ONE_NANOUNC
     }
 
-    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing.
-    ///
+    /// `as_picounc` is a function that converts number of atto-unc to an equivalent to the pico-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(5).saturating_add(UncToken::from_attounc(5)), UncToken::from_attounc(10));
-    /// assert_eq!(UncToken::from_attounc(u128::MAX).saturating_add(UncToken::from_attounc(1)), UncToken::from_attounc(u128::MAX));
+    /// assert_eq!(UncToken::from_attounc(10u128.pow(12)).as_picounc(), 1)
     /// ```
-    pub const fn saturating_add(self, rhs: Self) -> Self {
-        UncToken::from_attounc(self.as_attounc().saturating_add(rhs.as_attounc()))
+    pub const fn as_picounc(&self) -> u128 {
+        self.inner / ONE_PICOUNC
     }
 
-    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing.
-    ///
+    /// `as_femtounc` is a function that converts number of atto-unc to an equivalent to the femto-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(5).saturating_sub(UncToken::from_attounc(2)), UncToken::from_attounc(3));
-    /// assert_eq!(UncToken::from_attounc(1).saturating_sub(UncToken::from_attounc(2)), UncToken::from_attounc(0));
+    /// assert_eq!(UncToken::from_attounc(10u128.pow(9)).as_femtounc(), 1)
     /// ```
-    pub const fn saturating_sub(self, rhs: Self) -> Self {
-        UncToken::from_attounc(self.as_attounc().saturating_sub(rhs.as_attounc()))
+    pub const fn as_femtounc(&self) -> u128 {
+        self.inner / ONE_FEMTOUNC
     }
 
-    /// Saturating integer multiplication. Computes self * rhs, saturating at the numeric bounds instead of overflowing.
-    ///
+    /// `as_attounc` is a function that shows a number of atto-unc.
     /// # Examples
     /// ```
     /// use unc_token::UncToken;
-    /// use std::u128;
-    /// assert_eq!(UncToken::from_attounc(2).saturating_mul(5), UncToken::from_attounc(10));
-    /// assert_eq!(UncToken::from_attounc(u128::MAX).saturating_mul(2), UncToken::from_attounc(u128::MAX));
+    /// assert_eq!(UncToken::from_attounc(10).as_attounc(), 10)
     /// ```
-    pub const fn saturating_mul(self, rhs: u128) -> Self {
-        UncToken::from_attounc(self.as_attounc().saturating_mul(rhs))
+    pub const fn as_attounc(&self) -> u128 {
+        self.inner
     }
 
-    /// Saturating integer division. Computes self / rhs, saturating at the numeric bounds instead of overflowing.
-    ///
+    /// `display_rounded` returns a formatter that prints the token amount like the `Display` implementation does,
+    /// but rounds according to the given [`RoundingMode`] instead of always rounding up.
     /// # Examples
     /// ```
-    /// use unc_token::UncToken;
-    /// assert_eq!(UncToken::from_attounc(10).saturating_div(2), UncToken::from_attounc(5));
-    /// assert_eq!(UncToken::from_attounc(10).saturating_div(0), UncToken::from_attounc(0))
+    /// use unc_token::{RoundingMode, UncToken};
+    /// let balance = UncToken::from_attounc(10u128.pow(24) - 1);
+    /// assert_eq!(balance.to_string(), "1.00 UNC");
+    /// assert_eq!(balance.display_rounded(RoundingMode::Down).to_string(), "0.999 UNC");
+    /// assert_eq!(format!("{:.1}", balance.display_rounded(RoundingMode::Down)), "0.9 UNC");
     /// ```
-    pub const fn saturating_div(self, rhs: u128) -> Self {
-        if rhs == 0 {
-            return UncToken::from_attounc(0);
+    pub const fn display_rounded(self, rounding: RoundingMode) -> UncTokenDisplay {
+        UncTokenDisplay {
+            token: self,
+            rounding,
         }
-        UncToken::from_attounc(self.as_attounc().saturating_div(rhs))
+    }
+
+    /// `is_zero` is a boolian function that checks `UncToken`
+    /// if a `UncToken` inner is zero, returns true.
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// assert_eq!(UncToken::from_attounc(0).is_zero(), true)
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.inner == 0
     }
 }
 
+impl_checked_add_sub!([] UncToken, u128, from_attounc, as_attounc, "UncToken");
+impl_checked_mul_div!([] UncToken, u128, from_attounc, as_attounc, "UncToken");
+impl_checked_mul_ratio!([] UncToken, from_attounc, as_attounc, "UncToken");
+
 #[cfg(test)]
 mod test {
     use crate::UncToken;
@@ -289,6 +452,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn saturating_add_tokens() {
         let tokens = UncToken::from_attounc(100);
         let added_tokens = UncToken::from_attounc(1);
@@ -304,6 +468,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn saturating_sub_tokens() {
         let tokens = UncToken::from_attounc(100);
         let rhs_tokens = UncToken::from_attounc(1);
@@ -341,4 +506,66 @@ mod test {
             UncToken::from_attounc(0)
         );
     }
+
+    #[test]
+    fn checked_sum_tokens() {
+        let tokens = [
+            UncToken::from_attounc(u128::MAX - 3),
+            UncToken::from_attounc(3),
+        ];
+        assert_eq!(
+            UncToken::checked_sum(tokens),
+            Some(UncToken::from_attounc(u128::MAX))
+        );
+        assert_eq!(
+            UncToken::checked_sum(tokens.into_iter().chain([UncToken::from_attounc(1)])),
+            None
+        );
+        assert_eq!(
+            UncToken::checked_sum(std::iter::empty()),
+            Some(UncToken::from_attounc(0))
+        );
+    }
+
+    #[test]
+    fn checked_mul_div_tokens() {
+        use crate::RoundingMode;
+
+        let tokens = UncToken::from_attounc(u128::MAX);
+        assert_eq!(
+            tokens.checked_mul_div(7, 7, RoundingMode::Down),
+            Some(tokens)
+        );
+        assert_eq!(
+            tokens.checked_mul_div(u128::MAX, u128::MAX, RoundingMode::Up),
+            Some(tokens)
+        );
+        assert_eq!(tokens.checked_mul_div(2, 1, RoundingMode::Down), None);
+        assert_eq!(
+            tokens.checked_mul_div(u128::MAX - 1, u128::MAX, RoundingMode::Down),
+            Some(UncToken::from_attounc(u128::MAX - 1))
+        );
+        let tokens = UncToken::from_attounc(15);
+        for (rounding, expected) in [
+            (RoundingMode::Up, 2),
+            (RoundingMode::Down, 1),
+            (RoundingMode::TowardZero, 1),
+            (RoundingMode::HalfUp, 2),
+            (RoundingMode::HalfEven, 2),
+        ] {
+            assert_eq!(
+                tokens.checked_mul_div(1, 10, rounding),
+                Some(UncToken::from_attounc(expected))
+            );
+            assert_eq!(
+                tokens.mul_ratio((1, 10), rounding),
+                Some(UncToken::from_attounc(expected))
+            );
+        }
+        assert_eq!(
+            UncToken::from_attounc(25).checked_mul_div(1, 10, RoundingMode::HalfEven),
+            Some(UncToken::from_attounc(2))
+        );
+        assert_eq!(tokens.checked_mul_div(1, 0, RoundingMode::Down), None);
+    }
 }
@@ -0,0 +1,119 @@
+/// Creates an [`UncToken`](crate::UncToken) from a string literal with a unit, e.g. `unc!("1.25 UNC")`,
+/// checking the literal at compile time.
+///
+/// The literal accepts the same format as `UncToken::from_str` (see [`UncToken::parse_const`](crate::UncToken::parse_const)),
+/// and the macro can be used to initialize `const` and `static` items.
+///
+/// # Examples
+/// ```
+/// use unc_token::{unc, UncToken};
+///
+/// const STORAGE_DEPOSIT: UncToken = unc!("1.25 UNC");
+/// assert_eq!(STORAGE_DEPOSIT, UncToken::from_milliunc(1250));
+/// assert_eq!(unc!("500 milliUNC"), UncToken::from_milliunc(500));
+/// assert_eq!(unc!("1 aUNC"), UncToken::from_attounc(1));
+/// ```
+///
+/// Invalid literals fail the build:
+/// ```compile_fail
+/// let _ = unc_token::unc!("1.25 UAH");
+/// ```
+/// ```compile_fail
+/// let _ = unc_token::unc!("1.2.5 UNC");
+/// ```
+/// ```compile_fail
+/// let _ = unc_token::unc!("0.0000000000000000000000001 UNC");
+/// ```
+/// ```compile_fail
+/// let _ = unc_token::unc!("1000000000000000 UNC");
+/// ```
+#[macro_export]
+macro_rules! unc {
+    ($amount:literal) => {{
+        const AMOUNT: $crate::UncToken = match $crate::UncToken::parse_const($amount) {
+            ::core::result::Result::Ok(amount) => amount,
+            ::core::result::Result::Err(err) => match err.kind() {
+                $crate::ParseErrorKind::InvalidUnit => {
+                    ::core::panic!(::core::concat!("invalid token unit: ", $amount))
+                }
+                $crate::ParseErrorKind::InvalidNumber => {
+                    ::core::panic!(::core::concat!("invalid tokens amount: ", $amount))
+                }
+                $crate::ParseErrorKind::LongWhole | $crate::ParseErrorKind::OutOfRange => {
+                    ::core::panic!(::core::concat!(
+                        "tokens amount does not fit into u128 atto-unc: ",
+                        $amount
+                    ))
+                }
+                $crate::ParseErrorKind::LongFractional => ::core::panic!(::core::concat!(
+                    "tokens amount is more precise than 1 atto-unc: ",
+                    $amount
+                )),
+            },
+        };
+        AMOUNT
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ParseErrorKind, UncToken, ONE_UNC};
+
+    const AMOUNTS: [UncToken; 4] = [
+        crate::unc!("1.25 UNC"),
+        crate::unc!(" 2 kUNC "),
+        crate::unc!("0.000000000000000000000001 UNC"),
+        crate::unc!("340282366920938.463463374607431768211455 UNC"),
+    ];
+
+    #[test]
+    fn unc_literals() {
+        assert_eq!(
+            AMOUNTS,
+            [
+                UncToken::from_attounc(ONE_UNC + ONE_UNC / 4),
+                UncToken::from_kilounc(2),
+                UncToken::from_attounc(1),
+                UncToken::from_attounc(u128::MAX),
+            ]
+        );
+        for (literal, expected) in [
+            ("1.25 UNC", Ok(UncToken::from_milliunc(1250))),
+            (" 2 kUNC ", Ok(UncToken::from_kilounc(2))),
+            ("3 \u{b5}UNC", Ok(UncToken::from_microunc(3))),
+            ("1.5\u{a0}UNC", Ok(UncToken::from_milliunc(1500))),
+            ("-1 UNC", Err((ParseErrorKind::InvalidNumber, 0..1))),
+            ("1.2.5 UNC", Err((ParseErrorKind::InvalidNumber, 3..4))),
+            ("12.5", Err((ParseErrorKind::InvalidUnit, 4..4))),
+            ("1.25 UAH", Err((ParseErrorKind::InvalidUnit, 5..8))),
+            (
+                "0.0000000000000000000000001 UNC",
+                Err((ParseErrorKind::LongFractional, 2..27)),
+            ),
+            (
+                "1000000000000000 UNC",
+                Err((ParseErrorKind::LongWhole, 0..16)),
+            ),
+        ] {
+            assert_eq!(
+                UncToken::parse_const(literal).map_err(|err| (err.kind(), err.span())),
+                expected,
+                "input: {}",
+                literal
+            );
+        }
+    }
+
+    #[test]
+    fn unc_with_shadowed_result() {
+        #[allow(dead_code)]
+        enum Shadowed {
+            Ok,
+            Err,
+        }
+        #[allow(unused_imports)]
+        use Shadowed::{Err, Ok};
+
+        assert_eq!(crate::unc!("1 UNC"), UncToken::from_unc(1));
+    }
+}
@@ -0,0 +1,86 @@
+const LOW_MASK: u128 = u64::MAX as u128;
+
+/// Computes the full product `a * b` as `(high, low)` 128-bit halves.
+pub(crate) const fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let (a_high, a_low) = (a >> 64, a & LOW_MASK);
+    let (b_high, b_low) = (b >> 64, b & LOW_MASK);
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+    // At most 3 * (2^64 - 1), so it cannot overflow.
+    let middle = (low_low >> 64) + (low_high & LOW_MASK) + (high_low & LOW_MASK);
+    let low = (low_low & LOW_MASK) | (middle << 64);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Divides the 256-bit number `(high, low)` by `divisor`, returning the quotient and the remainder,
+/// or None if `divisor` is zero or the quotient does not fit into `u128`.
+pub(crate) const fn div_wide(high: u128, low: u128, divisor: u128) -> Option<(u128, u128)> {
+    if divisor == 0 || high >= divisor {
+        return None;
+    }
+    if high == 0 {
+        return Some((low / divisor, low % divisor));
+    }
+    // Binary long division; `remainder < divisor` holds before every step.
+    let mut quotient = 0;
+    let mut remainder = high;
+    let mut bit = 128;
+    while bit > 0 {
+        bit -= 1;
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// Computes `a * b / divisor` with a 256-bit intermediate product, returning the quotient and the remainder,
+/// or None if `divisor` is zero or the quotient does not fit into `u128`.
+pub(crate) const fn mul_div(a: u128, b: u128, divisor: u128) -> Option<(u128, u128)> {
+    let (high, low) = mul_wide(a, b);
+    div_wide(high, low, divisor)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mul_wide_test() {
+        assert_eq!(mul_wide(0, u128::MAX), (0, 0));
+        assert_eq!(mul_wide(3, 7), (0, 21));
+        assert_eq!(mul_wide(1 << 64, 1 << 64), (1, 0));
+        assert_eq!(mul_wide(u128::MAX, 2), (1, u128::MAX - 1));
+        assert_eq!(mul_wide(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+    }
+
+    #[test]
+    fn div_wide_test() {
+        assert_eq!(div_wide(0, 21, 7), Some((3, 0)));
+        assert_eq!(div_wide(1, 0, 2), Some((1 << 127, 0)));
+        assert_eq!(div_wide(1, u128::MAX - 1, u128::MAX), Some((2, 0)));
+        assert_eq!(div_wide(u128::MAX - 1, 1, u128::MAX), Some((u128::MAX, 0)));
+        assert_eq!(div_wide(1, 5, 3), Some((u128::MAX / 3 + 2, 0)));
+        assert_eq!(div_wide(1, 0, 1), None);
+        assert_eq!(div_wide(0, 1, 0), None);
+    }
+
+    #[test]
+    fn mul_div_test() {
+        let balance = 10u128.pow(38);
+        assert_eq!(mul_div(balance, 7, 10_000), Some((7 * 10u128.pow(34), 0)));
+        assert_eq!(
+            mul_div(u128::MAX, u128::MAX - 1, u128::MAX),
+            Some((u128::MAX - 1, 0))
+        );
+        assert_eq!(mul_div(10, 1, 3), Some((3, 1)));
+        assert_eq!(mul_div(u128::MAX, 2, 1), None);
+    }
+}
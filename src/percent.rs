@@ -0,0 +1,247 @@
+use crate::{RoundingMode, UncToken};
+
+/// Number of parts per million in 100%.
+pub(crate) const PPM_IN_ONE_HUNDRED_PERCENT: u32 = 1_000_000;
+
+/// Number of basis points in 100%.
+pub(crate) const BPS_IN_ONE_HUNDRED_PERCENT: u16 = 10_000;
+
+/// A percentage between 0% and 100% with up to four digits after the floating point, e.g. `2.5%`.
+///
+/// # Examples
+/// ```
+/// use unc_token::{Percent, RoundingMode, UncToken};
+///
+/// let commission: Percent = "2.5%".parse().unwrap();
+/// assert_eq!(commission.to_string(), "2.5%");
+/// assert_eq!(
+///     UncToken::from_unc(100).apply_percent(commission, RoundingMode::Down),
+///     UncToken::from_milliunc(2500)
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Percent {
+    ppm: u32,
+}
+
+impl Percent {
+    /// 0%
+    pub const ZERO: Percent = Percent { ppm: 0 };
+
+    /// 100%
+    pub const ONE_HUNDRED: Percent = Percent {
+        ppm: PPM_IN_ONE_HUNDRED_PERCENT,
+    };
+
+    /// `from_percent` is a function that takes value by a whole number of percent,
+    /// returning None if it is more than 100.
+    /// # Examples
+    /// ```
+    /// use unc_token::Percent;
+    /// assert_eq!(Percent::from_percent(5), Percent::from_ppm(50_000));
+    /// assert_eq!(Percent::from_percent(101), None);
+    /// ```
+    pub const fn from_percent(percent: u8) -> Option<Self> {
+        Self::from_ppm(percent as u32 * (PPM_IN_ONE_HUNDRED_PERCENT / 100))
+    }
+
+    /// `from_ppm` is a function that takes value by a number of parts per million (1% is 10 000 ppm),
+    /// returning None if it is more than 100%.
+    /// # Examples
+    /// ```
+    /// use unc_token::Percent;
+    /// assert_eq!(Percent::from_ppm(25_000).unwrap().to_string(), "2.5%");
+    /// assert_eq!(Percent::from_ppm(1_000_001), None);
+    /// ```
+    pub const fn from_ppm(ppm: u32) -> Option<Self> {
+        if ppm > PPM_IN_ONE_HUNDRED_PERCENT {
+            None
+        } else {
+            Some(Self { ppm })
+        }
+    }
+
+    /// `as_ppm` is a function that shows the percentage as a number of parts per million.
+    /// # Examples
+    /// ```
+    /// use unc_token::Percent;
+    /// assert_eq!(Percent::ONE_HUNDRED.as_ppm(), 1_000_000);
+    /// ```
+    pub const fn as_ppm(&self) -> u32 {
+        self.ppm
+    }
+}
+
+/// A fraction in basis points between 0 and 10 000 bps (100%), e.g. `25bps` for 0.25%.
+///
+/// # Examples
+/// ```
+/// use unc_token::{BasisPoints, UncToken};
+///
+/// let fee: BasisPoints = "25bps".parse().unwrap();
+/// let (fee, net) = UncToken::from_unc(100).fee_and_remainder(fee);
+/// assert_eq!(fee, UncToken::from_milliunc(250));
+/// assert_eq!(net, UncToken::from_milliunc(99_750));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BasisPoints {
+    bps: u16,
+}
+
+impl BasisPoints {
+    /// 0 bps
+    pub const ZERO: BasisPoints = BasisPoints { bps: 0 };
+
+    /// 10 000 bps (100%)
+    pub const ONE_HUNDRED_PERCENT: BasisPoints = BasisPoints {
+        bps: BPS_IN_ONE_HUNDRED_PERCENT,
+    };
+
+    /// `new` is a function that takes value by a number of basis points, returning None if it is more than 10 000.
+    /// # Examples
+    /// ```
+    /// use unc_token::BasisPoints;
+    /// assert_eq!(BasisPoints::new(25).unwrap().as_bps(), 25);
+    /// assert_eq!(BasisPoints::new(10_001), None);
+    /// ```
+    pub const fn new(bps: u16) -> Option<Self> {
+        if bps > BPS_IN_ONE_HUNDRED_PERCENT {
+            None
+        } else {
+            Some(Self { bps })
+        }
+    }
+
+    /// `as_bps` is a function that shows the number of basis points.
+    pub const fn as_bps(&self) -> u16 {
+        self.bps
+    }
+}
+
+impl From<BasisPoints> for Percent {
+    fn from(value: BasisPoints) -> Self {
+        Percent {
+            ppm: value.bps as u32
+                * (PPM_IN_ONE_HUNDRED_PERCENT / BPS_IN_ONE_HUNDRED_PERCENT as u32),
+        }
+    }
+}
+
+impl UncToken {
+    /// `apply_percent` is a function that computes the given percentage of the token amount,
+    /// rounding the result according to `rounding`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{Percent, RoundingMode, UncToken};
+    /// let half = Percent::from_percent(50).unwrap();
+    /// assert_eq!(UncToken::from_attounc(5).apply_percent(half, RoundingMode::Down), UncToken::from_attounc(2));
+    /// assert_eq!(UncToken::from_attounc(5).apply_percent(half, RoundingMode::Up), UncToken::from_attounc(3));
+    /// ```
+    pub const fn apply_percent(self, percent: Percent, rounding: RoundingMode) -> UncToken {
+        match self.checked_mul_div(
+            percent.ppm as u128,
+            PPM_IN_ONE_HUNDRED_PERCENT as u128,
+            rounding,
+        ) {
+            Some(result) => result,
+            // The percentage never exceeds 100%, so the result never exceeds `self`.
+            None => unreachable!(),
+        }
+    }
+
+    /// `apply_bps` is a function that computes the given number of basis points of the token amount,
+    /// rounding the result according to `rounding`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{BasisPoints, RoundingMode, UncToken};
+    /// let bps = BasisPoints::new(25).unwrap();
+    /// assert_eq!(UncToken::from_unc(1).apply_bps(bps, RoundingMode::Down), UncToken::from_attounc(25 * 10u128.pow(20)));
+    /// ```
+    pub const fn apply_bps(self, bps: BasisPoints, rounding: RoundingMode) -> UncToken {
+        match self.checked_mul_div(
+            bps.bps as u128,
+            BPS_IN_ONE_HUNDRED_PERCENT as u128,
+            rounding,
+        ) {
+            Some(result) => result,
+            // Basis points never exceed 100%, so the result never exceeds `self`.
+            None => unreachable!(),
+        }
+    }
+
+    /// `fee_and_remainder` is a function that splits the token amount into a fee of `bps` basis points
+    /// and the net amount that is left after the fee is taken.
+    ///
+    /// The fee is rounded up, so it is never underestimated, and the two parts always add up to `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{BasisPoints, UncToken};
+    /// let (fee, net) = UncToken::from_attounc(1001).fee_and_remainder(BasisPoints::new(10).unwrap());
+    /// assert_eq!(fee, UncToken::from_attounc(2));
+    /// assert_eq!(net, UncToken::from_attounc(999));
+    /// ```
+    pub const fn fee_and_remainder(self, bps: BasisPoints) -> (UncToken, UncToken) {
+        let fee = self.apply_bps(bps, RoundingMode::Up);
+        (fee, self.saturating_sub(fee))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BasisPoints, Percent, RoundingMode, UncToken};
+
+    #[test]
+    fn percent_from_basis_points() {
+        assert_eq!(
+            Percent::from(BasisPoints::new(25).unwrap()),
+            Percent::from_ppm(2_500).unwrap()
+        );
+        assert_eq!(
+            Percent::from(BasisPoints::ONE_HUNDRED_PERCENT),
+            Percent::ONE_HUNDRED
+        );
+    }
+
+    #[test]
+    fn apply_percent_tokens() {
+        let tokens = UncToken::from_attounc(u128::MAX);
+        assert_eq!(
+            tokens.apply_percent(Percent::ONE_HUNDRED, RoundingMode::Up),
+            tokens
+        );
+        assert_eq!(
+            tokens.apply_percent(Percent::ZERO, RoundingMode::Up),
+            UncToken::from_attounc(0)
+        );
+        assert_eq!(
+            tokens.apply_bps(BasisPoints::ONE_HUNDRED_PERCENT, RoundingMode::Up),
+            tokens
+        );
+        assert_eq!(
+            UncToken::from_unc(1000)
+                .apply_percent(Percent::from_ppm(12_345).unwrap(), RoundingMode::Down),
+            UncToken::from_milliunc(12_345)
+        );
+    }
+
+    #[test]
+    fn fee_and_remainder_tokens() {
+        for (amount, bps, expected_fee) in [
+            (0, 25, 0),
+            (1, 0, 0),
+            (1, 1, 1),
+            (10_000, 25, 25),
+            (10_001, 25, 26),
+            (u128::MAX, 10_000, u128::MAX),
+            (u128::MAX, 1, u128::MAX / 10_000 + 1),
+        ] {
+            let amount = UncToken::from_attounc(amount);
+            let (fee, net) = amount.fee_and_remainder(BasisPoints::new(bps).unwrap());
+            assert_eq!(fee, UncToken::from_attounc(expected_fee));
+            assert_eq!(fee.checked_add(net), Some(amount));
+        }
+    }
+}
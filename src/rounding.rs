@@ -0,0 +1,86 @@
+/// Rounding strategy used when a token amount is converted to a coarser unit or formatted
+/// with fewer digits than it has.
+///
+/// # Examples
+/// ```
+/// use unc_token::{RoundingMode, UncToken};
+///
+/// let balance = UncToken::from_milliunc(1500);
+/// assert_eq!(balance.as_unc_rounded(RoundingMode::Down), 1);
+/// assert_eq!(balance.as_unc_rounded(RoundingMode::Up), 2);
+/// assert_eq!(balance.as_unc_rounded(RoundingMode::HalfEven), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards positive infinity.
+    Up,
+    /// Round towards negative infinity.
+    Down,
+    /// Round to the nearest value, ties go to the even neighbour (banker's rounding).
+    HalfEven,
+    /// Round to the nearest value, ties go away from zero.
+    HalfUp,
+    /// Drop the digits that do not fit, i.e. truncate.
+    TowardZero,
+}
+
+impl RoundingMode {
+    /// Divides `n` by `d`, rounding the quotient according to `self`.
+    ///
+    /// `d` must not be zero.
+    pub(crate) const fn div(self, n: u128, d: u128) -> u128 {
+        let quotient = n / d;
+        quotient + self.rounds_up(quotient, n % d, d) as u128
+    }
+
+    /// Decides whether the truncated `quotient` of a division by `divisor` that left `remainder`
+    /// has to be incremented by one to be rounded according to `self`.
+    pub(crate) const fn rounds_up(self, quotient: u128, remainder: u128, divisor: u128) -> bool {
+        if remainder == 0 {
+            return false;
+        }
+        // `divisor - remainder` avoids overflowing `2 * remainder` for large divisors.
+        match self {
+            RoundingMode::Up => true,
+            RoundingMode::Down | RoundingMode::TowardZero => false,
+            RoundingMode::HalfUp => remainder >= divisor - remainder,
+            RoundingMode::HalfEven => {
+                remainder > divisor - remainder
+                    || (remainder == divisor - remainder && quotient % 2 == 1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RoundingMode;
+
+    #[test]
+    fn div_rounded() {
+        for (n, d, up, down, half_even, half_up) in [
+            (10, 5, 2, 2, 2, 2),
+            (11, 5, 3, 2, 2, 2),
+            (12, 5, 3, 2, 2, 2),
+            (13, 5, 3, 2, 3, 3),
+            (5, 2, 3, 2, 2, 3),
+            (7, 2, 4, 3, 4, 4),
+            (0, 7, 0, 0, 0, 0),
+            (u128::MAX, u128::MAX - 1, 2, 1, 1, 1),
+            (
+                u128::MAX,
+                2,
+                u128::MAX / 2 + 1,
+                u128::MAX / 2,
+                u128::MAX / 2 + 1,
+                u128::MAX / 2 + 1,
+            ),
+        ] {
+            assert_eq!(RoundingMode::Up.div(n, d), up, "{} / {}", n, d);
+            assert_eq!(RoundingMode::Down.div(n, d), down, "{} / {}", n, d);
+            assert_eq!(RoundingMode::TowardZero.div(n, d), down, "{} / {}", n, d);
+            assert_eq!(RoundingMode::HalfEven.div(n, d), half_even, "{} / {}", n, d);
+            assert_eq!(RoundingMode::HalfUp.div(n, d), half_up, "{} / {}", n, d);
+        }
+    }
+}
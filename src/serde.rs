@@ -0,0 +1,419 @@
+//! Alternative serde representations of [`UncToken`], selectable per field with `#[serde(with = "...")]`.
+//!
+//! | Module              | Serialized as                 | Deserialized from                                     |
+//! |---------------------|-------------------------------|-------------------------------------------------------|
+//! | [`as_attounc`]      | `"1500000000000000000000000"` | a string or an integer with an amount of atto-unc     |
+//! | [`as_unc_human`]    | `"1.5 UNC"`                   | any string accepted by `UncToken::from_str`           |
+//! | [`as_unc_decimal`]  | `"1.5"`                       | a string with a decimal number of UNC                 |
+//! | [`as_number`]       | `1500000000000000000000000`   | an integer number of atto-unc                         |
+//! | [`lenient`]         | like [`as_attounc`]           | any of the above                                      |
+//!
+//! [`as_attounc`] is the default representation of `UncToken`.
+//!
+//! With the **schemars** feature every module also has a `Schema` type that describes the representation
+//! in JSON schema. `schemars` cannot derive the schema from `#[serde(with = "...")]`, so point it to the type:
+//!
+//! ```ignore
+//! #[serde(with = "unc_token::serde::as_unc_decimal")]
+//! #[schemars(with = "unc_token::serde::as_unc_decimal::Schema")]
+//! amount: UncToken,
+//! ```
+//!
+//! # Examples
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use unc_token::UncToken;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Transfer {
+//!     #[serde(with = "unc_token::serde::as_unc_decimal")]
+//!     amount: UncToken,
+//!     #[serde(with = "unc_token::serde::as_number")]
+//!     fee: UncToken,
+//! }
+//!
+//! let transfer = Transfer {
+//!     amount: UncToken::from_milliunc(1500),
+//!     fee: UncToken::from_attounc(1000),
+//! };
+//! let json = serde_json::to_string(&transfer).unwrap();
+//! assert_eq!(json, r#"{"amount":"1.5","fee":1000}"#);
+//! ```
+
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::trait_impls::display::{ExactDecimal, UNC_DECIMALS};
+use crate::trait_impls::serde::{is_unsigned_integer, ATTOUNC_VISITOR};
+use crate::{UncToken, UncTokenError, ONE_UNC};
+
+/// The default representation: a string with an amount of atto-unc, e.g. `"1500000000000000000000000"`.
+///
+/// It is the same as the `Serialize` and `Deserialize` implementations of `UncToken`,
+/// and can be used to spell the choice out next to the fields with other representations.
+pub mod as_attounc {
+    use super::*;
+
+    /// The JSON schema of this representation, for `#[schemars(with = "unc_token::serde::as_attounc::Schema")]`.
+    #[cfg(feature = "schemars")]
+    pub struct Schema;
+
+    /// `serialize` is a function that writes the token amount as a string of atto-unc.
+    pub fn serialize<S: Serializer>(token: &UncToken, serializer: S) -> Result<S::Ok, S::Error> {
+        token.serialize(serializer)
+    }
+
+    /// `deserialize` is a function that reads the token amount from a string of atto-unc.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UncToken, D::Error> {
+        UncToken::deserialize(deserializer)
+    }
+}
+
+/// A human-readable string with a unit, e.g. `"1.5 UNC"`.
+///
+/// Amounts are serialized exactly (like the `{:#}` format), so they are never rounded,
+/// and any string accepted by `UncToken::from_str` is deserialized, e.g. `"1500 milliUNC"`.
+pub mod as_unc_human {
+    use super::*;
+
+    /// The JSON schema of this representation, for `#[schemars(with = "unc_token::serde::as_unc_human::Schema")]`.
+    #[cfg(feature = "schemars")]
+    pub struct Schema;
+
+    /// `serialize` is a function that writes the exact token amount in UNC with the unit.
+    pub fn serialize<S: Serializer>(token: &UncToken, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#}", token))
+    }
+
+    /// `deserialize` is a function that parses the token amount from a string with a unit.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UncToken, D::Error> {
+        deserializer.deserialize_str(HumanVisitor)
+    }
+}
+
+/// A string with a decimal number of UNC without the unit, e.g. `"1.5"`.
+pub mod as_unc_decimal {
+    use super::*;
+
+    /// The JSON schema of this representation, for `#[schemars(with = "unc_token::serde::as_unc_decimal::Schema")]`.
+    #[cfg(feature = "schemars")]
+    pub struct Schema;
+
+    /// `serialize` is a function that writes the exact token amount as a decimal number of UNC.
+    pub fn serialize<S: Serializer>(token: &UncToken, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&ExactDecimal {
+            amount: token.as_attounc(),
+            decimals: UNC_DECIMALS,
+        })
+    }
+
+    /// `deserialize` is a function that parses the token amount from a decimal number of UNC.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UncToken, D::Error> {
+        deserializer.deserialize_str(DecimalVisitor)
+    }
+}
+
+/// An integer number of atto-unc, e.g. `1000`.
+///
+/// Deserialization accepts the same numbers as the `Deserialize` implementation of `UncToken`.
+///
+/// Amounts that fit into `u64` are serialized as `u64`, larger amounts as `u128`, which is supported
+/// by `serde_json` and most binary formats. Keep in mind that JavaScript loses precision on numbers
+/// above 2^53, so prefer the string representations for amounts of whole UNC in public APIs.
+pub mod as_number {
+    use super::*;
+
+    /// The JSON schema of this representation, for `#[schemars(with = "unc_token::serde::as_number::Schema")]`.
+    #[cfg(feature = "schemars")]
+    pub struct Schema;
+
+    /// `serialize` is a function that writes the token amount as an integer number of atto-unc.
+    pub fn serialize<S: Serializer>(token: &UncToken, serializer: S) -> Result<S::Ok, S::Error> {
+        match u64::try_from(token.as_attounc()) {
+            Ok(attounc) => serializer.serialize_u64(attounc),
+            Err(_) => serializer.serialize_u128(token.as_attounc()),
+        }
+    }
+
+    /// `deserialize` is a function that reads the token amount from an integer number of atto-unc.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UncToken, D::Error> {
+        deserializer.deserialize_u128(NumberVisitor)
+    }
+}
+
+/// Serializes like [`as_attounc`] and deserializes any of the representations:
+///
+/// * a string of digits is an amount of atto-unc, e.g. `"1500"` or `"+1500"`;
+/// * a string of digits with a decimal point and no unit is an amount of UNC like in [`as_unc_decimal`],
+///   e.g. `"1.5"`;
+/// * any other string is parsed like `UncToken::from_str`, so it needs a unit, e.g. `"1.5 UNC"`;
+/// * a number is an amount of atto-unc, e.g. `1500`, with the same rules as the `Deserialize`
+///   implementation of `UncToken`.
+///
+/// Note that the decimal point decides the unit of a string without one: `"1"` is 1 atto-unc,
+/// while `"1.0"` is 1 UNC.
+///
+/// Deserialization relies on `Deserializer::deserialize_any`, so it is meant for self-describing formats like JSON.
+pub mod lenient {
+    use super::*;
+
+    /// The JSON schema of this representation, for `#[schemars(with = "unc_token::serde::lenient::Schema")]`.
+    #[cfg(feature = "schemars")]
+    pub struct Schema;
+
+    /// `serialize` is a function that writes the token amount as a string of atto-unc.
+    pub fn serialize<S: Serializer>(token: &UncToken, serializer: S) -> Result<S::Ok, S::Error> {
+        token.serialize(serializer)
+    }
+
+    /// `deserialize` is a function that reads the token amount from any of the supported representations.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UncToken, D::Error> {
+        deserializer.deserialize_any(LenientVisitor)
+    }
+}
+
+struct HumanVisitor;
+
+impl<'de> de::Visitor<'de> for HumanVisitor {
+    type Value = UncToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string with an amount of tokens and a unit, e.g. \"1.5 UNC\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        s.parse::<UncToken>()
+            .map_err(|err| E::custom(err.display(s)))
+    }
+}
+
+struct DecimalVisitor;
+
+impl<'de> de::Visitor<'de> for DecimalVisitor {
+    type Value = UncToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string with a decimal number of UNC, e.g. \"1.5\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        crate::utils::parse_decimal(s, ONE_UNC.ilog10())
+            .map(UncToken::from_attounc)
+            .map_err(|err| E::custom(UncTokenError::InvalidTokensAmount(err).display(s)))
+    }
+}
+
+struct NumberVisitor;
+
+/// Accepts the same numbers as the `Deserialize` implementation of `UncToken`, but no strings.
+impl<'de> de::Visitor<'de> for NumberVisitor {
+    type Value = UncToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a non-negative integer number of atto-unc")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_u64(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_u128(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_i64(v)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_i128(v)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_f64(v)
+    }
+}
+
+struct LenientVisitor;
+
+impl<'de> de::Visitor<'de> for LenientVisitor {
+    type Value = UncToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(
+            "an amount of atto-unc, a decimal number of UNC or an amount of tokens with a unit",
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        if is_unsigned_integer(s) {
+            ATTOUNC_VISITOR.visit_str(s)
+        } else if is_unitless_decimal(s) {
+            DecimalVisitor.visit_str(s)
+        } else {
+            HumanVisitor.visit_str(s)
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_u64(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_u128(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_i64(v)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_i128(v)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        ATTOUNC_VISITOR.visit_f64(v)
+    }
+}
+
+/// Returns whether `s` is a non-negative decimal number with a decimal point and without a unit, e.g. `1.5`.
+fn is_unitless_decimal(s: &str) -> bool {
+    s.split_once('.').is_some_and(|(whole, fractional)| {
+        is_unsigned_integer(whole)
+            && !fractional.is_empty()
+            && fractional.bytes().all(|b| b.is_ascii_digit())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::UncToken;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Amounts {
+        #[serde(with = "crate::serde::as_attounc")]
+        attounc: UncToken,
+        #[serde(with = "crate::serde::as_unc_human")]
+        human: UncToken,
+        #[serde(with = "crate::serde::as_unc_decimal")]
+        decimal: UncToken,
+        #[serde(with = "crate::serde::as_number")]
+        number: UncToken,
+        #[serde(with = "crate::serde::lenient")]
+        lenient: UncToken,
+    }
+
+    #[test]
+    fn round_trip() {
+        for (token, expected) in [
+            (
+                UncToken::from_milliunc(1500),
+                r#"{"attounc":"1500000000000000000000000","human":"1.5 UNC","decimal":"1.5","number":1500000000000000000000000,"lenient":"1500000000000000000000000"}"#,
+            ),
+            (
+                UncToken::from_attounc(1),
+                r#"{"attounc":"1","human":"0.000000000000000000000001 UNC","decimal":"0.000000000000000000000001","number":1,"lenient":"1"}"#,
+            ),
+            (
+                UncToken::from_attounc(0),
+                r#"{"attounc":"0","human":"0 UNC","decimal":"0","number":0,"lenient":"0"}"#,
+            ),
+        ] {
+            let amounts = Amounts {
+                attounc: token,
+                human: token,
+                decimal: token,
+                number: token,
+                lenient: token,
+            };
+            let json = serde_json::to_string(&amounts).unwrap();
+            assert_eq!(json, expected);
+            assert_eq!(serde_json::from_str::<Amounts>(&json).unwrap(), amounts);
+        }
+        let max = UncToken::from_attounc(u128::MAX);
+        let json = serde_json::to_string(&Amounts {
+            attounc: max,
+            human: max,
+            decimal: max,
+            number: max,
+            lenient: max,
+        })
+        .unwrap();
+        assert!(json.contains(r#""number":340282366920938463463374607431768211455"#));
+        assert_eq!(serde_json::from_str::<Amounts>(&json).unwrap().number, max);
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Lenient(#[serde(with = "crate::serde::lenient")] UncToken);
+
+    #[test]
+    fn lenient() {
+        for (json, expected) in [
+            (r#""1500""#, UncToken::from_attounc(1500)),
+            (r#""1.5 UNC""#, UncToken::from_milliunc(1500)),
+            (r#""1.5""#, UncToken::from_milliunc(1500)),
+            (
+                r#""+0.000000000000000000000001""#,
+                UncToken::from_attounc(1),
+            ),
+            (r#""1.0""#, UncToken::from_unc(1)),
+            (r#""1500 milliUNC""#, UncToken::from_milliunc(1500)),
+            ("1500", UncToken::from_attounc(1500)),
+            (
+                "18446744073709551615",
+                UncToken::from_attounc(u64::MAX.into()),
+            ),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<Lenient>(json).unwrap(),
+                Lenient(expected),
+                "input: {}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Decimal(#[serde(with = "crate::serde::as_unc_decimal")] UncToken);
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Number(#[serde(with = "crate::serde::as_number")] UncToken);
+
+        let err = serde_json::from_str::<Decimal>(r#""1.5 UNC""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid tokens amount: invalid number: 1.5 UNC at line 1 column 9"
+        );
+        // `serde_json` reports its own errors for integers that `deserialize_u128` cannot parse.
+        assert!(serde_json::from_str::<Number>(r#""1""#).is_err());
+        assert!(serde_json::from_str::<Number>("-1").is_err());
+        let err = serde_json::from_str::<Lenient>("-1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value: integer `-1`, expected a string or an integer with an amount of atto-unc at line 1 column 2"
+        );
+        let err = serde_json::from_str::<Lenient>(r#""0.0000000000000000000000001""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid tokens amount: too long fractional part: 0000000000000000000000001 at line 1 column 29"
+        );
+        let err = serde_json::from_str::<Lenient>(r#""1.""#).unwrap_err();
+        assert_eq!(err.to_string(), "invalid token unit: 1. at line 1 column 4");
+        let err = serde_json::from_str::<Lenient>(r#""1.5 UAH""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid token unit: 1.5 UAH at line 1 column 9"
+        );
+        let err = serde_json::from_str::<Lenient>(r#""340282366920938463463374607431768211456""#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "atto-unc amount does not fit into u128: 340282366920938463463374607431768211456 at line 1 column 41"
+        );
+    }
+}
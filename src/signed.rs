@@ -0,0 +1,398 @@
+use crate::{ConversionError, UncToken};
+
+/// A signed difference between two [`UncToken`] amounts, e.g. a balance change of -3.2 UNC.
+///
+/// The value is stored as a sign and an `UncToken` magnitude, so any difference between two
+/// `UncToken` values can be represented without overflow.
+///
+/// # Examples
+/// ```
+/// use unc_token::{SignedUncToken, UncToken};
+///
+/// let before = UncToken::from_milliunc(5000);
+/// let after = UncToken::from_milliunc(1800);
+/// let delta = UncToken::signed_diff(after, before);
+/// assert_eq!(delta, SignedUncToken::negative(UncToken::from_milliunc(3200)));
+/// assert_eq!(delta.to_string(), "-3.20 UNC");
+/// assert_eq!(before.checked_add_signed(delta), Some(after));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignedUncToken {
+    // Invariant: `negative` is never set for a zero magnitude, so every value has a single representation.
+    negative: bool,
+    magnitude: UncToken,
+}
+
+impl SignedUncToken {
+    /// Largest representable value, `+u128::MAX` atto-unc.
+    pub const MAX: SignedUncToken = SignedUncToken::positive(UncToken::from_attounc(u128::MAX));
+
+    /// Smallest representable value, `-u128::MAX` atto-unc.
+    pub const MIN: SignedUncToken = SignedUncToken::negative(UncToken::from_attounc(u128::MAX));
+
+    /// `from_attounc` is a function that takes value by a signed number of atto-unc.
+    /// # Examples
+    /// ```
+    /// use unc_token::{SignedUncToken, UncToken};
+    /// assert_eq!(SignedUncToken::from_attounc(-5), SignedUncToken::negative(UncToken::from_attounc(5)))
+    /// ```
+    pub const fn from_attounc(inner: i128) -> Self {
+        Self::from_sign_and_magnitude(inner < 0, UncToken::from_attounc(inner.unsigned_abs()))
+    }
+
+    /// `positive` is a function that creates a non-negative delta of the given magnitude.
+    /// # Examples
+    /// ```
+    /// use unc_token::{SignedUncToken, UncToken};
+    /// assert!(SignedUncToken::positive(UncToken::from_unc(1)).is_positive())
+    /// ```
+    pub const fn positive(magnitude: UncToken) -> Self {
+        Self::from_sign_and_magnitude(false, magnitude)
+    }
+
+    /// `negative` is a function that creates a non-positive delta of the given magnitude.
+    /// # Examples
+    /// ```
+    /// use unc_token::{SignedUncToken, UncToken};
+    /// assert!(SignedUncToken::negative(UncToken::from_unc(1)).is_negative());
+    /// assert!(SignedUncToken::negative(UncToken::from_unc(0)).is_zero());
+    /// ```
+    pub const fn negative(magnitude: UncToken) -> Self {
+        Self::from_sign_and_magnitude(true, magnitude)
+    }
+
+    const fn from_sign_and_magnitude(negative: bool, magnitude: UncToken) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// `as_attounc` is a function that shows a signed number of atto-unc,
+    /// returning None if the value does not fit into `i128`.
+    /// # Examples
+    /// ```
+    /// use unc_token::{SignedUncToken, UncToken};
+    /// assert_eq!(SignedUncToken::from_attounc(-10).as_attounc(), Some(-10));
+    /// assert_eq!(SignedUncToken::MAX.as_attounc(), None);
+    /// ```
+    pub const fn as_attounc(&self) -> Option<i128> {
+        let magnitude = self.magnitude.as_attounc();
+        if self.negative {
+            if magnitude <= i128::MAX as u128 {
+                Some(-(magnitude as i128))
+            } else if magnitude == i128::MIN.unsigned_abs() {
+                Some(i128::MIN)
+            } else {
+                None
+            }
+        } else if magnitude <= i128::MAX as u128 {
+            Some(magnitude as i128)
+        } else {
+            None
+        }
+    }
+
+    /// `magnitude` is a function that returns the absolute value of the delta.
+    /// # Examples
+    /// ```
+    /// use unc_token::{SignedUncToken, UncToken};
+    /// assert_eq!(SignedUncToken::from_attounc(-10).magnitude(), UncToken::from_attounc(10))
+    /// ```
+    pub const fn magnitude(&self) -> UncToken {
+        self.magnitude
+    }
+
+    /// `is_negative` is a boolian function that checks if the delta is less than zero.
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// `is_positive` is a boolian function that checks if the delta is greater than zero.
+    pub const fn is_positive(&self) -> bool {
+        !self.negative && !self.magnitude.is_zero()
+    }
+
+    /// `is_zero` is a boolian function that checks if the delta is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Checked integer addition. Computes self + rhs, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::SignedUncToken;
+    /// assert_eq!(SignedUncToken::from_attounc(2).checked_add(SignedUncToken::from_attounc(-5)), Some(SignedUncToken::from_attounc(-3)));
+    /// assert_eq!(SignedUncToken::MAX.checked_add(SignedUncToken::from_attounc(1)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (lhs_magnitude, rhs_magnitude) =
+            (self.magnitude.as_attounc(), rhs.magnitude.as_attounc());
+        if self.negative == rhs.negative {
+            match lhs_magnitude.checked_add(rhs_magnitude) {
+                Some(magnitude) => Some(Self::from_sign_and_magnitude(
+                    self.negative,
+                    UncToken::from_attounc(magnitude),
+                )),
+                None => None,
+            }
+        } else if lhs_magnitude >= rhs_magnitude {
+            Some(Self::from_sign_and_magnitude(
+                self.negative,
+                UncToken::from_attounc(lhs_magnitude - rhs_magnitude),
+            ))
+        } else {
+            Some(Self::from_sign_and_magnitude(
+                rhs.negative,
+                UncToken::from_attounc(rhs_magnitude - lhs_magnitude),
+            ))
+        }
+    }
+
+    /// Checked integer subtraction. Computes self - rhs, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::SignedUncToken;
+    /// assert_eq!(SignedUncToken::from_attounc(2).checked_sub(SignedUncToken::from_attounc(5)), Some(SignedUncToken::from_attounc(-3)));
+    /// assert_eq!(SignedUncToken::MIN.checked_sub(SignedUncToken::from_attounc(1)), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.neg())
+    }
+
+    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::SignedUncToken;
+    /// assert_eq!(SignedUncToken::from_attounc(-5).saturating_add(SignedUncToken::from_attounc(2)), SignedUncToken::from_attounc(-3));
+    /// assert_eq!(SignedUncToken::MIN.saturating_add(SignedUncToken::from_attounc(-1)), SignedUncToken::MIN);
+    /// ```
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(result) => result,
+            // Overflow is only possible when both operands have the same sign.
+            None if self.negative => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::SignedUncToken;
+    /// assert_eq!(SignedUncToken::from_attounc(5).saturating_sub(SignedUncToken::from_attounc(7)), SignedUncToken::from_attounc(-2));
+    /// assert_eq!(SignedUncToken::MAX.saturating_sub(SignedUncToken::from_attounc(-1)), SignedUncToken::MAX);
+    /// ```
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        self.saturating_add(rhs.neg())
+    }
+
+    const fn neg(self) -> Self {
+        Self::from_sign_and_magnitude(!self.negative, self.magnitude)
+    }
+}
+
+impl UncToken {
+    /// `signed_diff` is a function that computes the signed difference `self - rhs`, which never overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{SignedUncToken, UncToken};
+    /// assert_eq!(UncToken::signed_diff(UncToken::from_attounc(2), UncToken::from_attounc(5)), SignedUncToken::from_attounc(-3));
+    /// assert_eq!(UncToken::from_attounc(5).signed_diff(UncToken::from_attounc(2)), SignedUncToken::from_attounc(3));
+    /// ```
+    pub const fn signed_diff(self, rhs: Self) -> SignedUncToken {
+        if self.as_attounc() >= rhs.as_attounc() {
+            SignedUncToken::positive(UncToken::from_attounc(self.as_attounc() - rhs.as_attounc()))
+        } else {
+            SignedUncToken::negative(UncToken::from_attounc(rhs.as_attounc() - self.as_attounc()))
+        }
+    }
+
+    /// Checked addition of a signed delta. Computes self + rhs, returning None if the result
+    /// is negative or overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{SignedUncToken, UncToken};
+    /// assert_eq!(UncToken::from_attounc(5).checked_add_signed(SignedUncToken::from_attounc(-2)), Some(UncToken::from_attounc(3)));
+    /// assert_eq!(UncToken::from_attounc(5).checked_add_signed(SignedUncToken::from_attounc(-6)), None);
+    /// ```
+    pub const fn checked_add_signed(self, rhs: SignedUncToken) -> Option<Self> {
+        if rhs.is_negative() {
+            self.checked_sub(rhs.magnitude())
+        } else {
+            self.checked_add(rhs.magnitude())
+        }
+    }
+}
+
+impl core::ops::Neg for SignedUncToken {
+    type Output = SignedUncToken;
+
+    fn neg(self) -> SignedUncToken {
+        SignedUncToken::neg(self)
+    }
+}
+
+impl PartialOrd for SignedUncToken {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignedUncToken {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => core::cmp::Ordering::Greater,
+            (true, false) => core::cmp::Ordering::Less,
+        }
+    }
+}
+
+impl From<UncToken> for SignedUncToken {
+    fn from(value: UncToken) -> Self {
+        SignedUncToken::positive(value)
+    }
+}
+
+impl TryFrom<SignedUncToken> for UncToken {
+    type Error = ConversionError;
+
+    fn try_from(value: SignedUncToken) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            Err(ConversionError::NegativeAmount)
+        } else {
+            Ok(value.magnitude())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ConversionError, SignedUncToken, UncToken};
+
+    #[test]
+    fn signed_diff_tokens() {
+        let a = UncToken::from_attounc(u128::MAX);
+        let b = UncToken::from_attounc(0);
+        assert_eq!(a.signed_diff(b), SignedUncToken::MAX);
+        assert_eq!(b.signed_diff(a), SignedUncToken::MIN);
+        assert_eq!(a.signed_diff(a), SignedUncToken::from_attounc(0));
+        assert_eq!(b.checked_add_signed(a.signed_diff(b)), Some(a));
+        assert_eq!(a.checked_add_signed(b.signed_diff(a)), Some(b));
+    }
+
+    #[test]
+    fn checked_add_signed_tokens() {
+        let delta = SignedUncToken::from_attounc(-3);
+        assert_eq!(
+            delta.checked_add(SignedUncToken::from_attounc(3)),
+            Some(SignedUncToken::from_attounc(0))
+        );
+        assert!(!delta
+            .checked_add(SignedUncToken::from_attounc(3))
+            .unwrap()
+            .is_negative());
+        assert_eq!(
+            delta.checked_add(SignedUncToken::from_attounc(-3)),
+            Some(SignedUncToken::from_attounc(-6))
+        );
+        assert_eq!(
+            SignedUncToken::MAX.checked_add(SignedUncToken::MIN),
+            Some(SignedUncToken::from_attounc(0))
+        );
+        assert_eq!(SignedUncToken::MIN.checked_add(delta), None);
+    }
+
+    #[test]
+    fn checked_sub_signed_tokens() {
+        let delta = SignedUncToken::from_attounc(-3);
+        assert_eq!(
+            delta.checked_sub(SignedUncToken::from_attounc(-3)),
+            Some(SignedUncToken::from_attounc(0))
+        );
+        assert_eq!(
+            delta.checked_sub(SignedUncToken::from_attounc(2)),
+            Some(SignedUncToken::from_attounc(-5))
+        );
+        assert_eq!(SignedUncToken::MAX.checked_sub(delta), None);
+    }
+
+    #[test]
+    fn saturating_signed_tokens() {
+        let delta = SignedUncToken::from_attounc(10);
+        assert_eq!(
+            delta.saturating_sub(SignedUncToken::from_attounc(15)),
+            SignedUncToken::from_attounc(-5)
+        );
+        assert_eq!(
+            SignedUncToken::MAX.saturating_add(delta),
+            SignedUncToken::MAX
+        );
+        assert_eq!(
+            SignedUncToken::MIN.saturating_sub(delta),
+            SignedUncToken::MIN
+        );
+    }
+
+    #[test]
+    fn signed_tokens_ordering() {
+        let mut deltas = [
+            SignedUncToken::from_attounc(3),
+            SignedUncToken::MIN,
+            SignedUncToken::from_attounc(-3),
+            SignedUncToken::from_attounc(0),
+            SignedUncToken::MAX,
+            SignedUncToken::from_attounc(-1),
+        ];
+        deltas.sort();
+        assert_eq!(
+            deltas,
+            [
+                SignedUncToken::MIN,
+                SignedUncToken::from_attounc(-3),
+                SignedUncToken::from_attounc(-1),
+                SignedUncToken::from_attounc(0),
+                SignedUncToken::from_attounc(3),
+                SignedUncToken::MAX,
+            ]
+        );
+        assert_eq!(-SignedUncToken::MAX, SignedUncToken::MIN);
+        assert_eq!(
+            -SignedUncToken::from_attounc(0),
+            SignedUncToken::from_attounc(0)
+        );
+    }
+
+    #[test]
+    fn signed_tokens_conversions() {
+        assert_eq!(
+            SignedUncToken::from(UncToken::from_unc(1)),
+            SignedUncToken::positive(UncToken::from_unc(1))
+        );
+        assert_eq!(
+            UncToken::try_from(SignedUncToken::from_attounc(7)),
+            Ok(UncToken::from_attounc(7))
+        );
+        assert_eq!(
+            UncToken::try_from(SignedUncToken::from_attounc(-7)),
+            Err(ConversionError::NegativeAmount)
+        );
+        assert_eq!(
+            SignedUncToken::from_attounc(i128::MIN).as_attounc(),
+            Some(i128::MIN)
+        );
+        assert_eq!(
+            SignedUncToken::from_attounc(i128::MAX).as_attounc(),
+            Some(i128::MAX)
+        );
+        assert_eq!(SignedUncToken::MIN.as_attounc(), None);
+    }
+}
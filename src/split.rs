@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+
+use crate::UncToken;
+
+impl UncToken {
+    /// `split_by_weights` is a function that splits the token amount proportionally to `weights`.
+    ///
+    /// The parts always sum up exactly to the original amount: every part is first rounded down,
+    /// and the leftover atto-unc are handed out one by one to the parts with the largest remainders
+    /// (largest remainder method). Ties are resolved in favour of the part that comes first,
+    /// so the result is deterministic.
+    ///
+    /// Returns None if `weights` is empty, all weights are zero, or the sum of weights overflows `u128`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// let parts = UncToken::from_attounc(100).split_by_weights(&[1, 1, 1]).unwrap();
+    /// assert_eq!(parts, vec![UncToken::from_attounc(34), UncToken::from_attounc(33), UncToken::from_attounc(33)]);
+    /// assert_eq!(UncToken::from_attounc(100).split_by_weights(&[0, 0]), None);
+    /// ```
+    pub fn split_by_weights(self, weights: &[u128]) -> Option<Vec<UncToken>> {
+        let total_weight = weights
+            .iter()
+            .try_fold(0u128, |total, weight| total.checked_add(*weight))?;
+        if total_weight == 0 {
+            return None;
+        }
+        let amount = self.as_attounc();
+        let mut parts = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        let mut distributed = 0u128;
+        for (index, weight) in weights.iter().enumerate() {
+            // `weight <= total_weight`, so the quotient never exceeds `amount`.
+            let (part, remainder) = crate::math::mul_div(amount, *weight, total_weight)?;
+            distributed += part;
+            parts.push(part);
+            remainders.push((index, remainder));
+        }
+        // The sum of the rounded down parts is less than the amount by fewer than `weights.len()` atto-unc.
+        let leftover = (amount - distributed) as usize;
+        remainders.sort_by(|(lhs_index, lhs), (rhs_index, rhs)| {
+            rhs.cmp(lhs).then(lhs_index.cmp(rhs_index))
+        });
+        for (index, _) in remainders.into_iter().take(leftover) {
+            parts[index] += 1;
+        }
+        Some(parts.into_iter().map(UncToken::from_attounc).collect())
+    }
+
+    /// `split_evenly` is a function that splits the token amount into `n` parts that differ by at most one atto-unc.
+    ///
+    /// The parts always sum up exactly to the original amount; the leftover atto-unc go to the first parts.
+    /// Returns None if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// let parts = UncToken::from_attounc(11).split_evenly(3).unwrap();
+    /// assert_eq!(parts, vec![UncToken::from_attounc(4), UncToken::from_attounc(4), UncToken::from_attounc(3)]);
+    /// assert_eq!(UncToken::from_attounc(11).split_evenly(0), None);
+    /// ```
+    pub fn split_evenly(self, n: usize) -> Option<Vec<UncToken>> {
+        if n == 0 {
+            return None;
+        }
+        let amount = self.as_attounc();
+        let part = amount / n as u128;
+        let leftover = (amount % n as u128) as usize;
+        Some(
+            (0..n)
+                .map(|index| UncToken::from_attounc(part + (index < leftover) as u128))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::UncToken;
+
+    fn attounc(parts: Vec<UncToken>) -> Vec<u128> {
+        parts.into_iter().map(|part| part.as_attounc()).collect()
+    }
+
+    #[test]
+    fn split_by_weights_tokens() {
+        for (amount, weights, expected) in [
+            (100, vec![1, 1, 1], vec![34, 33, 33]),
+            (100, vec![1, 2, 3, 4], vec![10, 20, 30, 40]),
+            (10, vec![3, 3, 3], vec![4, 3, 3]),
+            (10, vec![1, 0, 1], vec![5, 0, 5]),
+            (7, vec![5, 1, 1], vec![5, 1, 1]),
+            (1, vec![1, 1, 1], vec![1, 0, 0]),
+            (0, vec![1, 1], vec![0, 0]),
+            (2, vec![1, 3, 3], vec![0, 1, 1]),
+            (u128::MAX, vec![u128::MAX], vec![u128::MAX]),
+            (u128::MAX, vec![u128::MAX - 1, 1], vec![u128::MAX - 1, 1]),
+            (
+                u128::MAX,
+                vec![u128::MAX / 2, u128::MAX / 2],
+                vec![u128::MAX / 2 + 1, u128::MAX / 2],
+            ),
+        ] {
+            let parts = UncToken::from_attounc(amount)
+                .split_by_weights(&weights)
+                .unwrap();
+            assert_eq!(attounc(parts.clone()), expected, "weights: {:?}", weights);
+            assert_eq!(
+                UncToken::checked_sum(parts),
+                Some(UncToken::from_attounc(amount))
+            );
+        }
+    }
+
+    #[test]
+    fn split_by_invalid_weights() {
+        let amount = UncToken::from_unc(1);
+        assert_eq!(amount.split_by_weights(&[]), None);
+        assert_eq!(amount.split_by_weights(&[0, 0]), None);
+        assert_eq!(amount.split_by_weights(&[u128::MAX, 1]), None);
+    }
+
+    #[test]
+    fn split_evenly_tokens() {
+        for (amount, n, expected) in [
+            (11, 3, vec![4, 4, 3]),
+            (12, 3, vec![4, 4, 4]),
+            (2, 3, vec![1, 1, 0]),
+            (5, 1, vec![5]),
+            (u128::MAX, 2, vec![u128::MAX / 2 + 1, u128::MAX / 2]),
+        ] {
+            let parts = UncToken::from_attounc(amount).split_evenly(n).unwrap();
+            assert_eq!(attounc(parts), expected);
+        }
+        assert_eq!(UncToken::from_attounc(11).split_evenly(0), None);
+    }
+}
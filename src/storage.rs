@@ -0,0 +1,198 @@
+use crate::UncToken;
+
+/// An amount of account storage in bytes.
+///
+/// Accounts have to lock UNC proportionally to the storage they use, see [`StorageUsage::storage_cost`]
+/// and [`StoragePrice::bytes_covered`].
+///
+/// # Examples
+/// ```
+/// use unc_token::{StoragePrice, StorageUsage, UncToken};
+///
+/// let usage = StorageUsage::from_bytes(2_000);
+/// assert_eq!(usage.storage_cost(StoragePrice::DEFAULT), Some(UncToken::from_milliunc(20)));
+/// assert_eq!(StoragePrice::DEFAULT.bytes_covered(UncToken::from_unc(1)), Some(StorageUsage::from_bytes(100_000)));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct StorageUsage {
+    bytes: u64,
+}
+
+impl StorageUsage {
+    /// `from_bytes` is a function that takes value by a number of bytes.
+    /// # Examples
+    /// ```
+    /// use unc_token::StorageUsage;
+    /// assert_eq!(StorageUsage::from_bytes(10).as_bytes(), 10)
+    /// ```
+    pub const fn from_bytes(bytes: u64) -> Self {
+        Self { bytes }
+    }
+
+    /// `as_bytes` is a function that shows a number of bytes.
+    pub const fn as_bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// `is_zero` is a boolian function that checks if no storage is used.
+    pub const fn is_zero(&self) -> bool {
+        self.bytes == 0
+    }
+
+    /// Checked storage cost. Computes the amount of tokens that has to be locked for the storage
+    /// at the given price per byte, returning None if it does not fit into `UncToken`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{StoragePrice, StorageUsage, UncToken};
+    /// let price = StoragePrice::from_attounc_per_byte(10u128.pow(19));
+    /// assert_eq!(StorageUsage::from_bytes(100_000).storage_cost(price), Some(UncToken::from_unc(1)));
+    /// assert_eq!(StorageUsage::from_bytes(u64::MAX).storage_cost(StoragePrice::from_attounc_per_byte(u128::MAX)), None);
+    /// ```
+    pub const fn storage_cost(self, price: StoragePrice) -> Option<UncToken> {
+        match (self.bytes as u128).checked_mul(price.as_attounc_per_byte()) {
+            Some(attounc) => Some(UncToken::from_attounc(attounc)),
+            None => None,
+        }
+    }
+}
+
+impl_checked_add_sub!([] StorageUsage, u64, from_bytes, as_bytes, "StorageUsage");
+
+/// The amount of atto-unc that has to be locked for every byte of storage.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct StoragePrice {
+    attounc_per_byte: u128,
+}
+
+impl StoragePrice {
+    /// The protocol storage price: 10^19 atto-unc per byte, so 1 UNC covers 100 kB.
+    pub const DEFAULT: StoragePrice = StoragePrice::from_attounc_per_byte(10_u128.pow(19));
+
+    /// `from_attounc_per_byte` is a function that takes value by a number of atto-unc per byte.
+    /// # Examples
+    /// ```
+    /// use unc_token::StoragePrice;
+    /// assert_eq!(StoragePrice::from_attounc_per_byte(10u128.pow(19)), StoragePrice::DEFAULT)
+    /// ```
+    pub const fn from_attounc_per_byte(attounc_per_byte: u128) -> Self {
+        Self { attounc_per_byte }
+    }
+
+    /// `as_attounc_per_byte` is a function that shows a number of atto-unc per byte.
+    pub const fn as_attounc_per_byte(&self) -> u128 {
+        self.attounc_per_byte
+    }
+
+    /// Computes how many bytes of storage `deposit` covers at this price, rounding down.
+    ///
+    /// Returns None if the price is zero or the deposit covers more than `u64::MAX` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{StoragePrice, StorageUsage, UncToken};
+    /// let deposit = UncToken::from_milliunc(1);
+    /// assert_eq!(StoragePrice::DEFAULT.bytes_covered(deposit), Some(StorageUsage::from_bytes(100)));
+    /// assert_eq!(StoragePrice::from_attounc_per_byte(0).bytes_covered(deposit), None);
+    /// assert_eq!(StoragePrice::from_attounc_per_byte(1).bytes_covered(UncToken::from_unc(1)), None);
+    /// ```
+    pub const fn bytes_covered(self, deposit: UncToken) -> Option<StorageUsage> {
+        match deposit.as_attounc().checked_div(self.attounc_per_byte) {
+            Some(bytes) if bytes <= u64::MAX as u128 => {
+                Some(StorageUsage::from_bytes(bytes as u64))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The default storage price is [`StoragePrice::DEFAULT`].
+impl Default for StoragePrice {
+    fn default() -> Self {
+        StoragePrice::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{StoragePrice, StorageUsage, UncToken};
+
+    #[test]
+    fn storage_usage_arithmetic() {
+        let max = StorageUsage::from_bytes(u64::MAX);
+        assert_eq!(max.checked_add(StorageUsage::from_bytes(1)), None);
+        assert_eq!(max.saturating_add(StorageUsage::from_bytes(1)), max);
+        assert_eq!(
+            StorageUsage::default().checked_sub(StorageUsage::from_bytes(1)),
+            None
+        );
+        assert_eq!(
+            StorageUsage::default().saturating_sub(StorageUsage::from_bytes(1)),
+            StorageUsage::default()
+        );
+        assert!(StorageUsage::default().is_zero());
+    }
+
+    #[test]
+    fn storage_cost() {
+        assert_eq!(
+            StorageUsage::from_bytes(1).storage_cost(StoragePrice::default()),
+            Some(UncToken::from_attounc(10u128.pow(19)))
+        );
+        assert_eq!(
+            StorageUsage::from_bytes(0)
+                .storage_cost(StoragePrice::from_attounc_per_byte(u128::MAX)),
+            Some(UncToken::from_attounc(0))
+        );
+        assert_eq!(
+            StorageUsage::from_bytes(u64::MAX).storage_cost(StoragePrice::from_attounc_per_byte(
+                u128::MAX / u64::MAX as u128
+            )),
+            Some(UncToken::from_attounc(u128::MAX))
+        );
+        assert_eq!(
+            StorageUsage::from_bytes(2)
+                .storage_cost(StoragePrice::from_attounc_per_byte(u128::MAX / 2 + 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn bytes_covered() {
+        let price = StoragePrice::from_attounc_per_byte(3);
+        assert_eq!(
+            price.bytes_covered(UncToken::from_attounc(10)),
+            Some(StorageUsage::from_bytes(3))
+        );
+        let usage = StorageUsage::from_bytes(1_234);
+        assert_eq!(
+            StoragePrice::DEFAULT.bytes_covered(usage.storage_cost(StoragePrice::DEFAULT).unwrap()),
+            Some(usage)
+        );
+        let price = StoragePrice::from_attounc_per_byte(1);
+        assert_eq!(
+            price.bytes_covered(UncToken::from_attounc(u64::MAX.into())),
+            Some(StorageUsage::from_bytes(u64::MAX))
+        );
+        assert_eq!(
+            price.bytes_covered(UncToken::from_attounc(u64::MAX as u128 + 1)),
+            None
+        );
+        assert_eq!(
+            StoragePrice::from_attounc_per_byte(0).bytes_covered(UncToken::from_unc(1)),
+            None
+        );
+    }
+}
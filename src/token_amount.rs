@@ -0,0 +1,221 @@
+use crate::{RoundingMode, UncToken};
+
+/// An amount of a fungible token with `DECIMALS` digits after the floating point, stored as
+/// an integer number of the token's base units, e.g. `TokenAmount<6>` for a token where
+/// 1 token is 10^6 base units.
+///
+/// Amounts with a different number of decimals are distinct types, so they can't be mixed by accident.
+/// Parsing, formatting, arithmetic and the serde/borsh representations work the same way as for
+/// [`UncToken`], which converts losslessly to and from `TokenAmount<24>`.
+///
+/// `DECIMALS` can be at most 38, as `10^39` does not fit into `u128`: the constructors and
+/// [`TokenAmount::as_base_units`] fail to compile for larger values.
+///
+/// ```compile_fail
+/// let one = unc_token::TokenAmount::<39>::ONE;
+/// ```
+/// ```compile_fail
+/// let amount = unc_token::TokenAmount::<39>::from_base_units(5);
+/// ```
+/// ```compile_fail
+/// let amount = unc_token::TokenAmount::<39>::default();
+/// ```
+///
+/// # Examples
+/// ```
+/// use unc_token::{TokenAmount, UncToken};
+///
+/// type Usdc = TokenAmount<6>;
+///
+/// let price: Usdc = "2.5".parse().unwrap();
+/// assert_eq!(price, Usdc::from_base_units(2_500_000));
+/// assert_eq!((price * 3).to_string(), "7.5");
+/// assert_eq!(format!("{:.2}", price), "2.50");
+/// assert_eq!(TokenAmount::<24>::from(UncToken::from_unc(1)), TokenAmount::ONE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct TokenAmount<const DECIMALS: u8> {
+    inner: u128,
+}
+
+impl<const DECIMALS: u8> TokenAmount<DECIMALS> {
+    /// Number of base units in one whole token, `10^DECIMALS`.
+    const BASE_UNITS_IN_ONE: u128 = match 10u128.checked_pow(DECIMALS as u32) {
+        Some(base_units) => base_units,
+        None => panic!("TokenAmount supports at most 38 decimals"),
+    };
+
+    /// Zero tokens.
+    pub const ZERO: Self = Self::from_base_units(0);
+
+    /// One whole token, `10^DECIMALS` base units.
+    pub const ONE: Self = Self::from_base_units(Self::BASE_UNITS_IN_ONE);
+
+    /// Largest representable amount, `u128::MAX` base units.
+    pub const MAX: Self = Self::from_base_units(u128::MAX);
+
+    /// `from_base_units` is a function that takes value by a number of base units.
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenAmount;
+    /// assert_eq!(TokenAmount::<6>::from_base_units(1_000_000), TokenAmount::<6>::ONE)
+    /// ```
+    pub const fn from_base_units(inner: u128) -> Self {
+        // Evaluating the constant fails the build for more than 38 decimals.
+        let _ = Self::BASE_UNITS_IN_ONE;
+        Self { inner }
+    }
+
+    /// `from_whole` is a function that takes value by a number of whole tokens and converts it to an equivalent to the base units.
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenAmount;
+    /// assert_eq!(TokenAmount::<6>::from_whole(2), TokenAmount::<6>::from_base_units(2_000_000))
+    /// ```
+    pub const fn from_whole(inner: u128) -> Self {
+        Self {
+            inner: inner * Self::BASE_UNITS_IN_ONE,
+        }
+    }
+
+    /// `as_base_units` is a function that shows a number of base units.
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenAmount;
+    /// assert_eq!(TokenAmount::<6>::from_base_units(10).as_base_units(), 10)
+    /// ```
+    pub const fn as_base_units(&self) -> u128 {
+        // Borsh deserializes the amount without a constructor, so the number of decimals is checked here too.
+        let _ = Self::BASE_UNITS_IN_ONE;
+        self.inner
+    }
+
+    /// `as_whole` is a function that converts number of base units to an equivalent to the whole tokens,
+    /// dropping the fractional part.
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenAmount;
+    /// assert_eq!(TokenAmount::<6>::from_base_units(2_999_999).as_whole(), 2)
+    /// ```
+    pub const fn as_whole(&self) -> u128 {
+        self.inner / Self::BASE_UNITS_IN_ONE
+    }
+
+    /// `as_whole_rounded` is a function that converts number of base units to an equivalent to the whole tokens,
+    /// rounding the result according to the given [`RoundingMode`].
+    /// # Examples
+    /// ```
+    /// use unc_token::{RoundingMode, TokenAmount};
+    /// let balance = TokenAmount::<6>::from_base_units(2_500_000);
+    /// assert_eq!(balance.as_whole_rounded(RoundingMode::HalfEven), 2);
+    /// assert_eq!(balance.as_whole_rounded(RoundingMode::HalfUp), 3);
+    /// ```
+    pub const fn as_whole_rounded(&self, rounding: RoundingMode) -> u128 {
+        rounding.div(self.inner, Self::BASE_UNITS_IN_ONE)
+    }
+
+    /// `is_zero` is a boolian function that checks `TokenAmount`
+    /// if a `TokenAmount` inner is zero, returns true.
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenAmount;
+    /// assert!(TokenAmount::<6>::ZERO.is_zero())
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.inner == 0
+    }
+}
+
+/// The default amount is [`TokenAmount::ZERO`].
+impl<const DECIMALS: u8> Default for TokenAmount<DECIMALS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl_checked_add_sub!([const DECIMALS: u8] TokenAmount<DECIMALS>, u128, from_base_units, as_base_units, "TokenAmount<6>");
+impl_checked_mul_div!([const DECIMALS: u8] TokenAmount<DECIMALS>, u128, from_base_units, as_base_units, "TokenAmount<6>");
+impl_checked_mul_ratio!([const DECIMALS: u8] TokenAmount<DECIMALS>, from_base_units, as_base_units, "TokenAmount<6>");
+
+/// One UNC has 24 decimals, so `UncToken` and `TokenAmount<24>` hold the same amounts.
+impl From<UncToken> for TokenAmount<24> {
+    fn from(value: UncToken) -> Self {
+        TokenAmount::from_base_units(value.as_attounc())
+    }
+}
+
+impl From<TokenAmount<24>> for UncToken {
+    fn from(value: TokenAmount<24>) -> Self {
+        UncToken::from_attounc(value.as_base_units())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{RoundingMode, TokenAmount, UncToken};
+
+    type Usdc = TokenAmount<6>;
+    type Wbtc = TokenAmount<8>;
+
+    #[test]
+    fn whole_and_base_units() {
+        assert_eq!(Usdc::ONE.as_base_units(), 1_000_000);
+        assert_eq!(Wbtc::ONE.as_base_units(), 100_000_000);
+        assert_eq!(TokenAmount::<0>::ONE.as_base_units(), 1);
+        assert_eq!(TokenAmount::<38>::ONE.as_base_units(), 10u128.pow(38));
+        assert_eq!(Usdc::from_whole(3).as_whole(), 3);
+        assert_eq!(Usdc::from_base_units(1_499_999).as_whole(), 1);
+        assert_eq!(
+            Usdc::from_base_units(1_500_000).as_whole_rounded(RoundingMode::HalfUp),
+            2
+        );
+        assert_eq!(
+            Usdc::from_base_units(1).as_whole_rounded(RoundingMode::Up),
+            1
+        );
+        assert!(Usdc::default().is_zero());
+    }
+
+    #[test]
+    fn checked_and_saturating_arithmetic() {
+        assert_eq!(Usdc::MAX.checked_add(Usdc::from_base_units(1)), None);
+        assert_eq!(Usdc::ZERO.checked_sub(Usdc::from_base_units(1)), None);
+        assert_eq!(Usdc::MAX.checked_mul(2), None);
+        assert_eq!(Usdc::ONE.checked_div(0), None);
+        assert_eq!(
+            Usdc::ONE.checked_mul_div(1, 3, RoundingMode::Down),
+            Some(Usdc::from_base_units(333_333))
+        );
+        assert_eq!(
+            Usdc::MAX.checked_mul_div(u128::MAX, u128::MAX, RoundingMode::Up),
+            Some(Usdc::MAX)
+        );
+        assert_eq!(Usdc::MAX.mul_ratio((2, 1), RoundingMode::Down), None);
+        assert_eq!(
+            Usdc::checked_sum([Usdc::ONE, Usdc::ONE]),
+            Some(Usdc::from_whole(2))
+        );
+        assert_eq!(Usdc::MAX.saturating_add(Usdc::ONE), Usdc::MAX);
+        assert_eq!(Usdc::ZERO.saturating_sub(Usdc::ONE), Usdc::ZERO);
+        assert_eq!(Usdc::MAX.saturating_mul(3), Usdc::MAX);
+        assert_eq!(Usdc::ONE.saturating_div(0), Usdc::ZERO);
+    }
+
+    #[test]
+    fn unc_token_conversions() {
+        let tokens = UncToken::from_milliunc(1500);
+        let amount = TokenAmount::<24>::from(tokens);
+        assert_eq!(amount.as_base_units(), tokens.as_attounc());
+        assert_eq!(UncToken::from(amount), tokens);
+        assert_eq!(
+            UncToken::from(TokenAmount::<24>::ONE),
+            UncToken::from_unc(1)
+        );
+    }
+}
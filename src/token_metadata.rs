@@ -0,0 +1,106 @@
+use alloc::string::String;
+
+/// The symbol and the number of decimals of a fungible token, as learned at runtime
+/// from its metadata, e.g. `USDT` with 6 decimals.
+///
+/// Use [`TokenMetadata::amount`] and [`TokenMetadata::parse_amount`] to format and parse
+/// amounts of the token with its symbol. When the number of decimals is known at compile time,
+/// prefer [`TokenAmount`](crate::TokenAmount).
+///
+/// # Examples
+/// ```
+/// use unc_token::TokenMetadata;
+///
+/// let usdt = TokenMetadata::new("USDT", 6).unwrap();
+/// let amount = usdt.parse_amount("12.5 USDT").unwrap();
+/// assert_eq!(amount.as_base_units(), 12_500_000);
+/// assert_eq!(amount.to_string(), "12.50 USDT");
+/// assert_eq!(usdt.amount(1).to_string(), "<0.001 USDT");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenMetadata {
+    symbol: String,
+    decimals: u8,
+}
+
+impl TokenMetadata {
+    /// `new` is a function that creates the metadata of a token with the given symbol and number of decimals,
+    /// returning None if there are more than 38 decimals, as `10^39` base units do not fit into `u128`.
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenMetadata;
+    /// assert_eq!(TokenMetadata::new("wBTC", 8).unwrap().decimals(), 8);
+    /// assert_eq!(TokenMetadata::new("HUGE", 39), None);
+    /// ```
+    pub fn new(symbol: impl Into<String>, decimals: u8) -> Option<Self> {
+        10u128.checked_pow(decimals.into())?;
+        Some(Self {
+            symbol: symbol.into(),
+            decimals,
+        })
+    }
+
+    /// `symbol` is a function that shows the symbol of the token.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// `decimals` is a function that shows the number of digits after the floating point of the token.
+    pub const fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// `amount` is a function that creates an amount of the token from a number of base units.
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenMetadata;
+    /// let usdt = TokenMetadata::new("USDT", 6).unwrap();
+    /// assert_eq!(usdt.amount(2_000_000).to_string(), "2.00 USDT");
+    /// ```
+    pub const fn amount(&self, base_units: u128) -> DynTokenAmount<'_> {
+        DynTokenAmount {
+            base_units,
+            metadata: self,
+        }
+    }
+}
+
+/// An amount of a fungible token described by runtime [`TokenMetadata`].
+///
+/// It is displayed like `UncToken`, with the symbol of the token instead of `UNC`:
+/// with the same breakpoints by default (`12.50 USDT`, `0.005 USDT`, `<0.001 USDT`),
+/// the exact amount with the alternate flag (`{:#}`) and a fixed number of digits with a precision (`{:.4}`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynTokenAmount<'a> {
+    base_units: u128,
+    metadata: &'a TokenMetadata,
+}
+
+impl<'a> DynTokenAmount<'a> {
+    /// `as_base_units` is a function that shows a number of base units.
+    pub const fn as_base_units(&self) -> u128 {
+        self.base_units
+    }
+
+    /// `metadata` is a function that shows the metadata of the token the amount is of.
+    pub const fn metadata(&self) -> &'a TokenMetadata {
+        self.metadata
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::TokenMetadata;
+
+    #[test]
+    fn token_metadata() {
+        let usdt = TokenMetadata::new(String::from("USDT"), 6).unwrap();
+        assert_eq!(usdt.symbol(), "USDT");
+        assert_eq!(usdt.decimals(), 6);
+        let amount = usdt.amount(42);
+        assert_eq!(amount.as_base_units(), 42);
+        assert_eq!(amount.metadata(), &usdt);
+        assert!(TokenMetadata::new("", 38).is_some());
+        assert!(TokenMetadata::new("", 39).is_none());
+    }
+}
@@ -0,0 +1,162 @@
+use alloc::string::ToString;
+
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::BigDecimal;
+
+use crate::trait_impls::display::UNC_DECIMALS;
+use crate::utils::parse_decimal;
+use crate::{DecimalNumberParsingError, InputFragment, UncToken, UncTokenError};
+
+/// The largest scale (and negated scale) of a `BigDecimal` that is parsed from its plain notation.
+/// Larger exponents would spell out as many zeros, while no such number is a valid amount anyway.
+const PLAIN_SCALE_LIMIT: i64 = 64;
+
+/// The amount is converted to a number of UNC with a scale of 24, so every atto-unc is kept.
+///
+/// # Examples
+/// ```
+/// use bigdecimal::BigDecimal;
+/// use unc_token::UncToken;
+///
+/// let decimal = BigDecimal::from(UncToken::from_milliunc(1_500));
+/// assert_eq!(decimal, "1.5".parse::<BigDecimal>().unwrap());
+/// assert_eq!(decimal.fractional_digit_count(), 24);
+/// ```
+impl From<UncToken> for BigDecimal {
+    fn from(value: UncToken) -> Self {
+        BigDecimal::new(BigInt::from(value.as_attounc()), UNC_DECIMALS as i64)
+    }
+}
+
+/// A `BigDecimal` is read as a number of UNC, the same way as the number part of `"1.5 UNC"` is parsed:
+/// negative numbers, fractions of an atto-unc and amounts that do not fit into `UncToken`
+/// are rejected with `UncTokenError::InvalidTokensAmount`. The spans of the error are byte ranges of the number
+/// in plain notation, or of its `Display` in scientific notation if the exponent is beyond ±64.
+///
+/// # Examples
+/// ```
+/// use bigdecimal::BigDecimal;
+/// use unc_token::UncToken;
+///
+/// let decimal: BigDecimal = "1.5".parse().unwrap();
+/// assert_eq!(UncToken::try_from(&decimal), Ok(UncToken::from_milliunc(1_500)));
+/// assert!(UncToken::try_from(-decimal).is_err());
+/// assert!(UncToken::try_from("1e-25".parse::<BigDecimal>().unwrap()).is_err());
+/// ```
+impl TryFrom<&BigDecimal> for UncToken {
+    type Error = UncTokenError;
+
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        // Trailing zeros beyond 24 fractional digits do not make the amount more precise than an atto-unc.
+        let value = value.normalized();
+        let scale = value.fractional_digit_count();
+        if (-PLAIN_SCALE_LIMIT..=PLAIN_SCALE_LIMIT).contains(&scale) {
+            let number = value.to_plain_string();
+            return parse_decimal(&number, UNC_DECIMALS as u32)
+                .map(UncToken::from_attounc)
+                .map_err(UncTokenError::InvalidTokensAmount);
+        }
+
+        // The scientific notation, e.g. `1e-100`, is quoted in the error instead.
+        let number = 0..value.to_string().len();
+        let err = if value.sign() == Sign::Minus {
+            DecimalNumberParsingError::InvalidNumber(InputFragment::new(number, 0..1))
+        } else if scale > 0 {
+            DecimalNumberParsingError::LongFractional(InputFragment::new(number.clone(), number))
+        } else {
+            DecimalNumberParsingError::LongWhole(InputFragment::new(number.clone(), number))
+        };
+        Err(UncTokenError::InvalidTokensAmount(err))
+    }
+}
+
+/// See the implementation for `&BigDecimal`.
+impl TryFrom<BigDecimal> for UncToken {
+    type Error = UncTokenError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        UncToken::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+
+    use crate::{DecimalNumberParsingError, InputFragment, UncToken, UncTokenError};
+
+    fn decimal(s: &str) -> BigDecimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn big_decimal_to_unc_token() {
+        assert_eq!(
+            UncToken::try_from(decimal("0.000000000000000000000001")),
+            Ok(UncToken::from_attounc(1))
+        );
+        assert_eq!(
+            UncToken::try_from(decimal("1.000000000000000000000000000000")),
+            Ok(UncToken::from_unc(1))
+        );
+        assert_eq!(
+            UncToken::try_from(decimal("3e14")),
+            Ok(UncToken::from_unc(300_000_000_000_000))
+        );
+        assert_eq!(
+            UncToken::try_from(decimal("340282366920938.463463374607431768211455")),
+            Ok(UncToken::from_attounc(u128::MAX))
+        );
+        assert_eq!(
+            UncToken::try_from(decimal("340282366920938.463463374607431768211456")),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::OutOfRange(InputFragment::new(0..40, 0..40))
+            ))
+        );
+
+        let err = UncToken::try_from(decimal("-0.5")).unwrap_err();
+        assert_eq!(
+            err.display("-0.5").to_string(),
+            "invalid tokens amount: invalid number: -0.5"
+        );
+        assert_eq!(err.span(), 0..1);
+        assert_eq!(
+            UncToken::try_from(decimal("1.0000000000000000000000001")),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::LongFractional(InputFragment::new(2..27, 2..27))
+            ))
+        );
+    }
+
+    #[test]
+    fn big_decimal_with_huge_exponent() {
+        assert!(matches!(
+            UncToken::try_from(decimal("1e-1000000000")),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::LongFractional(_)
+            ))
+        ));
+        assert!(matches!(
+            UncToken::try_from(decimal("1e1000000000")),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::LongWhole(_)
+            ))
+        ));
+        let err = UncToken::try_from(decimal("-1e1000000000")).unwrap_err();
+        assert!(matches!(
+            err,
+            UncTokenError::InvalidTokensAmount(DecimalNumberParsingError::InvalidNumber(_))
+        ));
+        assert_eq!(err.span(), 0..1);
+    }
+
+    #[test]
+    fn unc_token_to_big_decimal() {
+        for attounc in [0, 1, 10u128.pow(24), u128::MAX] {
+            let amount = UncToken::from_attounc(attounc);
+            let decimal = BigDecimal::from(amount);
+            assert_eq!(decimal.fractional_digit_count(), 24);
+            assert_eq!(UncToken::try_from(&decimal), Ok(amount));
+        }
+    }
+}
@@ -2,7 +2,7 @@
 mod test {
     use borsh::{to_vec, BorshDeserialize};
 
-    use crate::UncToken;
+    use crate::{GasPrice, StoragePrice, StorageUsage, TokenAmount, UncGas, UncToken};
 
     #[test]
     fn borsh() {
@@ -24,4 +24,40 @@ mod test {
         test_borsh_ser(8, [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
         test_borsh_ser(0, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
     }
+
+    #[test]
+    fn borsh_token_amount() {
+        let amount = TokenAmount::<6>::from_base_units(2_500_000);
+        let ser = to_vec(&amount).unwrap();
+        assert_eq!(ser, to_vec(&amount.as_base_units()).unwrap());
+        assert_eq!(TokenAmount::<6>::try_from_slice(&ser).unwrap(), amount);
+    }
+
+    #[test]
+    fn borsh_gas() {
+        let gas = UncGas::from_tgas(30);
+        let ser = to_vec(&gas).unwrap();
+        assert_eq!(ser, 30_000_000_000_000u64.to_le_bytes());
+        assert_eq!(UncGas::try_from_slice(&ser).unwrap(), gas);
+
+        let price = GasPrice::from_attounc(100_000_000);
+        let ser = to_vec(&price).unwrap();
+        assert_eq!(ser, 100_000_000u128.to_le_bytes());
+        assert_eq!(GasPrice::try_from_slice(&ser).unwrap(), price);
+    }
+
+    #[test]
+    fn borsh_storage() {
+        let usage = StorageUsage::from_bytes(2_000);
+        let ser = to_vec(&usage).unwrap();
+        assert_eq!(ser, 2_000u64.to_le_bytes());
+        assert_eq!(StorageUsage::try_from_slice(&ser).unwrap(), usage);
+
+        let ser = to_vec(&StoragePrice::DEFAULT).unwrap();
+        assert_eq!(ser, 10u128.pow(19).to_le_bytes());
+        assert_eq!(
+            StoragePrice::try_from_slice(&ser).unwrap(),
+            StoragePrice::DEFAULT
+        );
+    }
 }
@@ -0,0 +1,13 @@
+use clap::builder::ValueParserFactory;
+
+use crate::clap::UncTokenValueParser;
+use crate::UncToken;
+
+/// `clap::value_parser!(UncToken)`, and so clap derive, parses `UncToken` arguments with [`UncTokenValueParser`].
+impl ValueParserFactory for UncToken {
+    type Parser = UncTokenValueParser;
+
+    fn value_parser() -> Self::Parser {
+        UncTokenValueParser::new()
+    }
+}
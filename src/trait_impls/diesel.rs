@@ -0,0 +1,104 @@
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+
+use crate::UncToken;
+
+/// In PostgreSQL an amount is stored as a `NUMERIC` number of atto-unc, e.g. in a `NUMERIC(39, 0)` column
+/// (`u128::MAX` has 39 digits).
+#[cfg(feature = "diesel-postgres")]
+mod postgres {
+    use std::io::Write;
+
+    use diesel::pg::{Pg, PgValue};
+    use diesel::sql_types::Numeric;
+
+    use super::*;
+    use crate::trait_impls::pg_numeric::{decode_numeric, encode_numeric, MAX_NUMERIC_LEN};
+
+    impl ToSql<Numeric, Pg> for UncToken {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            out.write_all(encode_numeric(*self, &mut [0; MAX_NUMERIC_LEN]))?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl FromSql<Numeric, Pg> for UncToken {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            decode_numeric(value.as_bytes())
+        }
+    }
+}
+
+/// In SQLite an amount is stored as the `TEXT` of its number of atto-unc, as SQLite integers only have 64 bits.
+#[cfg(feature = "diesel-sqlite")]
+mod sqlite {
+    use diesel::sql_types::Text;
+    use diesel::sqlite::{Sqlite, SqliteValue};
+
+    use super::*;
+
+    impl ToSql<Text, Sqlite> for UncToken {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(self.as_attounc().to_string());
+            Ok(IsNull::No)
+        }
+    }
+
+    impl FromSql<Text, Sqlite> for UncToken {
+        fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+            let text = <String as FromSql<Text, Sqlite>>::from_sql(value)?;
+            Ok(UncToken::from_attounc(text.parse()?))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "diesel-sqlite"))]
+mod test {
+    use diesel::prelude::*;
+    use diesel::sql_types::Text;
+
+    use crate::UncToken;
+
+    diesel::table! {
+        balances (id) {
+            id -> Integer,
+            amount -> Text,
+        }
+    }
+
+    #[test]
+    fn sqlite_round_trip() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query("CREATE TABLE balances (id INTEGER PRIMARY KEY, amount TEXT NOT NULL)")
+            .execute(&mut conn)
+            .unwrap();
+        let amounts = [
+            UncToken::from_attounc(0),
+            UncToken::from_unc(1),
+            UncToken::from_attounc(u128::MAX),
+        ];
+        for amount in amounts {
+            diesel::insert_into(balances::table)
+                .values(balances::amount.eq(amount))
+                .execute(&mut conn)
+                .unwrap();
+        }
+
+        let stored: Vec<UncToken> = balances::table
+            .select(balances::amount)
+            .order(balances::id)
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(stored, amounts);
+        let text: String = balances::table
+            .select(balances::amount)
+            .filter(balances::id.eq(3))
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(text, u128::MAX.to_string());
+
+        let negative =
+            diesel::select(diesel::dsl::sql::<Text>("'-1'")).get_result::<UncToken>(&mut conn);
+        assert!(negative.is_err());
+    }
+}
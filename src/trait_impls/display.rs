@@ -1,4 +1,13 @@
-use crate::{UncToken, ONE_MILLIUNC};
+use core::fmt::Write;
+
+use crate::percent::PPM_IN_ONE_HUNDRED_PERCENT;
+use crate::{BasisPoints, Percent, RoundingMode, SignedUncToken, TokenAmount, UncGas, UncToken};
+
+/// Number of decimal digits after the floating point in one UNC.
+pub(crate) const UNC_DECIMALS: usize = 24;
+
+/// Number of decimal digits after the floating point in one Tgas.
+const TERA_GAS_DECIMALS: usize = 12;
 
 /// UncToken Display implementation rounds up the token amount to the relevant precision point.
 /// There are 4 breakpoints:
@@ -6,29 +15,407 @@ use crate::{UncToken, ONE_MILLIUNC};
 /// 2. <0.001 UNC
 /// 3. 0.001 - 0.999 UNC (uses 3 digits after the floating point)
 /// 4. >1 UNC (uses 2 digits after the floating point)
-impl std::fmt::Display for UncToken {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if *self == UncToken::from_attounc(0) {
-            write!(f, "0 UNC")
-        } else if *self < UncToken::from_milliunc(1) {
-            write!(f, "<0.001 UNC")
-        } else if *self <= UncToken::from_milliunc(999) {
-            let milliunc_rounded_up =
-                self.as_attounc().saturating_add(ONE_MILLIUNC - 1) / ONE_MILLIUNC;
-            write!(f, "0.{:03} UNC", milliunc_rounded_up)
+///
+/// The alternate flag (`{:#}`) prints the exact amount instead: every significant atto-unc digit
+/// is kept and trailing zeros are trimmed, so the output can be parsed back with `UncToken::from_str`.
+///
+/// An explicit precision (`{:.6}`) replaces the breakpoints with the given number of digits after
+/// the floating point, still rounding up. Width, fill and alignment (`{:>20}`, `{:*^20}`) pad the
+/// whole `"x.yz UNC"` string and default to left alignment, like strings do.
+impl core::fmt::Display for UncToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.display_rounded(RoundingMode::Up), f)
+    }
+}
+
+/// Formatter returned by [`UncToken::display_rounded`].
+///
+/// It follows the same breakpoints and formatting flags as the `Display` implementation of `UncToken`,
+/// but rounds the printed digits with the configured [`RoundingMode`].
+/// Amounts between 0 and 0.001 UNC are still printed as `<0.001 UNC`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UncTokenDisplay {
+    pub(crate) token: UncToken,
+    pub(crate) rounding: RoundingMode,
+}
+
+impl core::fmt::Display for UncTokenDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let attounc = self.token.as_attounc();
+        let rounding = self.rounding;
+        let precision = f.precision();
+        let alternate = f.alternate();
+        pad(f, |w| {
+            fmt_amount(
+                attounc,
+                UNC_DECIMALS,
+                "UNC",
+                precision,
+                alternate,
+                rounding,
+                w,
+            )
+        })
+    }
+}
+
+/// SignedUncToken Display implementation prints an explicit `+` or `-` sign in front of the magnitude,
+/// which is formatted exactly like `UncToken` (e.g. `+1.20 UNC`, `-0.50 UNC`). Zero has no sign: `0 UNC`.
+impl core::fmt::Display for SignedUncToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.is_negative() {
+            "-"
+        } else if self.is_positive() {
+            "+"
         } else {
-            let unc_rounded_up =
-                self.as_attounc().saturating_add(10 * ONE_MILLIUNC - 1) / ONE_MILLIUNC / 10;
-            write!(
-                f,
-                "{}.{:02} UNC",
-                unc_rounded_up / 100,
-                unc_rounded_up % 100
+            ""
+        };
+        let attounc = self.magnitude().as_attounc();
+        let precision = f.precision();
+        let alternate = f.alternate();
+        pad(f, |w| {
+            w.write_str(sign)?;
+            fmt_amount(
+                attounc,
+                UNC_DECIMALS,
+                "UNC",
+                precision,
+                alternate,
+                RoundingMode::Up,
+                w,
             )
+        })
+    }
+}
+
+/// TokenAmount Display implementation prints the exact number of whole tokens with trailing zeros trimmed
+/// and without a unit, e.g. `2.5`, so the output can be parsed back with `TokenAmount::from_str`.
+///
+/// An explicit precision (`{:.2}`) prints the given number of digits after the floating point, rounding up
+/// like `UncToken` does. Width, fill and alignment pad the number and default to left alignment.
+impl<const DECIMALS: u8> core::fmt::Display for TokenAmount<DECIMALS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let amount = self.as_base_units();
+        let decimals = DECIMALS.into();
+        let precision = f.precision();
+        pad(f, |w| match precision {
+            Some(precision) => fmt_fixed(amount, decimals, precision, RoundingMode::Up, w),
+            None => write!(w, "{}", ExactDecimal { amount, decimals }),
+        })
+    }
+}
+
+/// DynTokenAmount Display implementation formats the amount like `UncToken` does, using the symbol
+/// and the number of decimals from the token metadata, e.g. `12.50 USDT`.
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for crate::DynTokenAmount<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let amount = self.as_base_units();
+        let metadata = self.metadata();
+        let precision = f.precision();
+        let alternate = f.alternate();
+        pad(f, |w| {
+            fmt_amount(
+                amount,
+                metadata.decimals().into(),
+                metadata.symbol(),
+                precision,
+                alternate,
+                RoundingMode::Up,
+                w,
+            )
+        })
+    }
+}
+
+/// UncGas Display implementation prints the amount in Tgas with the same breakpoints and formatting flags
+/// as `UncToken`, e.g. `30.00 Tgas`, `0.005 Tgas` or `<0.001 Tgas` for less than 1 Ggas.
+impl core::fmt::Display for UncGas {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let gas = self.as_gas().into();
+        let precision = f.precision();
+        let alternate = f.alternate();
+        pad(f, |w| {
+            fmt_amount(
+                gas,
+                TERA_GAS_DECIMALS,
+                "Tgas",
+                precision,
+                alternate,
+                RoundingMode::Up,
+                w,
+            )
+        })
+    }
+}
+
+/// Percent Display implementation prints the exact percentage with trailing zeros trimmed, e.g. `2.5%`.
+impl core::fmt::Display for Percent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let ppm_in_one_percent = PPM_IN_ONE_HUNDRED_PERCENT / 100;
+        let whole = self.as_ppm() / ppm_in_one_percent;
+        let mut fractional = self.as_ppm() % ppm_in_one_percent;
+        if fractional == 0 {
+            return write!(f, "{}%", whole);
+        }
+        let mut digits = 4;
+        while fractional % 10 == 0 {
+            fractional /= 10;
+            digits -= 1;
+        }
+        write!(f, "{}.{:0digits$}%", whole, fractional, digits = digits)
+    }
+}
+
+/// BasisPoints Display implementation, e.g. `25bps`.
+impl core::fmt::Display for BasisPoints {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}bps", self.as_bps())
+    }
+}
+
+/// Scientific notation of the amount in UNC, e.g. `1.5e3 UNC` for 1500 UNC.
+///
+/// Without a precision every significant digit is printed; with a precision (`{:.2e}`) the mantissa
+/// is rounded up to the given number of digits after the floating point.
+impl core::fmt::LowerExp for UncToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let attounc = self.as_attounc();
+        let precision = f.precision();
+        pad(f, |w| fmt_scientific(attounc, precision, 'e', w))
+    }
+}
+
+/// Same as the [`LowerExp`](core::fmt::LowerExp) implementation, but with an upper-case `E`.
+impl core::fmt::UpperExp for UncToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let attounc = self.as_attounc();
+        let precision = f.precision();
+        pad(f, |w| fmt_scientific(attounc, precision, 'E', w))
+    }
+}
+
+/// Counts the number of characters written, so that the padding can be computed without allocating.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes the output of `write` into `f`, padded according to the width, fill and alignment of `f`.
+fn pad(
+    f: &mut core::fmt::Formatter<'_>,
+    write: impl Fn(&mut dyn Write) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let Some(width) = f.width() else {
+        return write(f);
+    };
+    let mut counter = CharCounter(0);
+    write(&mut counter)?;
+    let padding = width.saturating_sub(counter.0);
+    let (pre, post) = match f.align() {
+        Some(core::fmt::Alignment::Right) => (padding, 0),
+        Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(core::fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    write(f)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Writes `amount` base units of a token with `decimals` digits after the floating point, followed by the `symbol`,
+/// in the format selected by the formatter flags, see the `Display` implementation of `UncToken`.
+fn fmt_amount(
+    amount: u128,
+    decimals: usize,
+    symbol: &str,
+    precision: Option<usize>,
+    alternate: bool,
+    rounding: RoundingMode,
+    w: &mut dyn Write,
+) -> core::fmt::Result {
+    match precision {
+        Some(precision) => {
+            fmt_fixed(amount, decimals, precision, rounding, w)?;
+            write!(w, " {}", symbol)
         }
+        None if alternate => write!(w, "{} {}", ExactDecimal { amount, decimals }, symbol),
+        None => fmt_breakpoints(amount, decimals, symbol, rounding, w),
     }
 }
 
+/// Writes `amount` using the default breakpoints described on the `Display` implementation of `UncToken`.
+///
+/// The breakpoint is picked after rounding, so e.g. 0.9999 UNC rounded up is printed as `1.00 UNC`.
+fn fmt_breakpoints(
+    amount: u128,
+    decimals: usize,
+    symbol: &str,
+    rounding: RoundingMode,
+    w: &mut dyn Write,
+) -> core::fmt::Result {
+    if amount == 0 {
+        return write!(w, "0 {}", symbol);
+    }
+    let milli_rounded = match (decimals as u32).checked_sub(3) {
+        Some(exponent) => {
+            let one_milli = 10u128.pow(exponent);
+            if amount < one_milli {
+                return write!(w, "<0.001 {}", symbol);
+            }
+            rounding.div(amount, one_milli)
+        }
+        // A token with less than 3 decimals has no amounts below 0.001 and needs no rounding.
+        None => amount.saturating_mul(10u128.pow(3 - decimals as u32)),
+    };
+    if milli_rounded < 1000 {
+        write!(w, "0.{:03} {}", milli_rounded, symbol)
+    } else {
+        fmt_fixed(amount, decimals, 2, rounding, w)?;
+        write!(w, " {}", symbol)
+    }
+}
+
+/// Displays an amount of base units as a decimal number of whole tokens without any rounding and without the unit,
+/// e.g. `1.5` or `0.000000000000000000000001`.
+pub(crate) struct ExactDecimal {
+    pub(crate) amount: u128,
+    pub(crate) decimals: usize,
+}
+
+impl core::fmt::Display for ExactDecimal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let one = 10u128.pow(self.decimals as u32);
+        let whole = self.amount / one;
+        let mut fractional = self.amount % one;
+        if fractional == 0 {
+            return write!(f, "{}", whole);
+        }
+        let mut digits = self.decimals;
+        while fractional % 10 == 0 {
+            fractional /= 10;
+            digits -= 1;
+        }
+        write!(f, "{}.{:0digits$}", whole, fractional, digits = digits)
+    }
+}
+
+/// Writes `amount` base units of a token with `decimals` digits after the floating point
+/// with exactly `precision` digits after the floating point, without the unit.
+fn fmt_fixed(
+    amount: u128,
+    decimals: usize,
+    precision: usize,
+    rounding: RoundingMode,
+    w: &mut dyn Write,
+) -> core::fmt::Result {
+    if precision >= decimals {
+        let one = 10u128.pow(decimals as u32);
+        write!(w, "{}", amount / one)?;
+        if precision > 0 {
+            w.write_char('.')?;
+        }
+        if decimals > 0 {
+            write!(w, "{:0decimals$}", amount % one, decimals = decimals)?;
+        }
+        for _ in decimals..precision {
+            w.write_char('0')?;
+        }
+        return Ok(());
+    }
+    let scale = 10u128.pow((decimals - precision) as u32);
+    let rounded = rounding.div(amount, scale);
+    let one = 10u128.pow(precision as u32);
+    if precision == 0 {
+        write!(w, "{}", rounded)
+    } else {
+        write!(
+            w,
+            "{}.{:0precision$}",
+            rounded / one,
+            rounded % one,
+            precision = precision
+        )
+    }
+}
+
+/// Writes `attounc` in scientific notation with the mantissa in `[1, 10)` and the exponent
+/// relative to one UNC, e.g. `1.5e-3 UNC`.
+fn fmt_scientific(
+    attounc: u128,
+    precision: Option<usize>,
+    exp_char: char,
+    w: &mut dyn Write,
+) -> core::fmt::Result {
+    let Some(last_digit) = attounc.checked_ilog10() else {
+        return match precision {
+            Some(precision) if precision > 0 => {
+                write!(
+                    w,
+                    "0.{:0precision$}{}0 UNC",
+                    0,
+                    exp_char,
+                    precision = precision
+                )
+            }
+            _ => write!(w, "0{}0 UNC", exp_char),
+        };
+    };
+    let mut exponent = last_digit as i32 - UNC_DECIMALS as i32;
+    // Significant digits of the mantissa and how many of them go after the floating point.
+    let (mantissa, fractional_digits) = match precision {
+        Some(precision) => {
+            let precision = precision.min(last_digit as usize);
+            let mut mantissa =
+                RoundingMode::Up.div(attounc, 10u128.pow(last_digit - precision as u32));
+            if mantissa == 10u128.pow(precision as u32 + 1) {
+                // Rounding up carried over into a new digit, e.g. 9.99 -> 10.0
+                mantissa /= 10;
+                exponent += 1;
+            }
+            (mantissa, precision)
+        }
+        None => {
+            let mut mantissa = attounc;
+            let mut fractional_digits = last_digit as usize;
+            while mantissa % 10 == 0 {
+                mantissa /= 10;
+                fractional_digits -= 1;
+            }
+            (mantissa, fractional_digits)
+        }
+    };
+    let scale = 10u128.pow(fractional_digits as u32);
+    if fractional_digits == 0 {
+        write!(w, "{}", mantissa)?;
+        if precision.unwrap_or(0) > 0 {
+            w.write_char('.')?;
+        }
+    } else {
+        write!(
+            w,
+            "{}.{:0fractional_digits$}",
+            mantissa / scale,
+            mantissa % scale,
+            fractional_digits = fractional_digits
+        )?;
+    }
+    // Requested digits beyond the ones stored in the amount are zeros.
+    for _ in fractional_digits..precision.unwrap_or(0) {
+        w.write_char('0')?;
+    }
+    write!(w, "{}{} UNC", exp_char, exponent)
+}
+
 #[cfg(test)]
 mod test {
     use crate::UncToken;
@@ -102,4 +489,320 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_display_exact() {
+        for (unc_tokens, expected_display) in [
+            (UncToken::from_attounc(0), "0 UNC"),
+            (UncToken::from_attounc(1), "0.000000000000000000000001 UNC"),
+            (UncToken::from_milliunc(1), "0.001 UNC"),
+            (UncToken::from_milliunc(1234), "1.234 UNC"),
+            (UncToken::from_unc(10), "10 UNC"),
+            (
+                UncToken::from_attounc(10u128.pow(24) + 1),
+                "1.000000000000000000000001 UNC",
+            ),
+            (
+                UncToken::from_attounc(u128::MAX),
+                "340282366920938.463463374607431768211455 UNC",
+            ),
+        ] {
+            assert_eq!(format!("{:#}", unc_tokens), expected_display);
+        }
+    }
+
+    #[test]
+    fn test_display_exact_round_trip() {
+        for attounc in [
+            0,
+            1,
+            10,
+            999,
+            10u128.pow(21) + 1,
+            10u128.pow(24) - 1,
+            123_456_789_012_345_678_901_234_567,
+            u128::MAX,
+        ] {
+            let unc_tokens = UncToken::from_attounc(attounc);
+            let displayed = format!("{:#}", unc_tokens);
+            assert_eq!(displayed.parse::<UncToken>(), Ok(unc_tokens));
+            assert_eq!(
+                format!("{:#}", displayed.parse::<UncToken>().unwrap()),
+                displayed
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_precision() {
+        for (unc_tokens, precision, expected_display) in [
+            (UncToken::from_attounc(0), 2, "0.00 UNC"),
+            (UncToken::from_attounc(0), 0, "0 UNC"),
+            (UncToken::from_attounc(1), 6, "0.000001 UNC"),
+            (UncToken::from_milliunc(1234), 0, "2 UNC"),
+            (UncToken::from_milliunc(1234), 1, "1.3 UNC"),
+            (UncToken::from_milliunc(1234), 3, "1.234 UNC"),
+            (UncToken::from_milliunc(1234), 6, "1.234000 UNC"),
+            (UncToken::from_unc(5), 24, "5.000000000000000000000000 UNC"),
+            (
+                UncToken::from_attounc(10u128.pow(24) + 1),
+                26,
+                "1.00000000000000000000000100 UNC",
+            ),
+            (
+                UncToken::from_attounc(u128::MAX),
+                2,
+                "340282366920938.47 UNC",
+            ),
+        ] {
+            assert_eq!(format!("{:.*}", precision, unc_tokens), expected_display);
+        }
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let unc_tokens = UncToken::from_milliunc(1500);
+        assert_eq!(format!("{:12}", unc_tokens), "1.50 UNC    ");
+        assert_eq!(format!("{:<12}", unc_tokens), "1.50 UNC    ");
+        assert_eq!(format!("{:>12}", unc_tokens), "    1.50 UNC");
+        assert_eq!(format!("{:*^12}", unc_tokens), "**1.50 UNC**");
+        assert_eq!(format!("{:>12.1}", unc_tokens), "     1.5 UNC");
+        assert_eq!(format!("{:>#12}", unc_tokens), "     1.5 UNC");
+        assert_eq!(format!("{:2}", unc_tokens), "1.50 UNC");
+        assert_eq!(format!("{:>8e}", unc_tokens), "1.5e0 UNC");
+        assert_eq!(format!("{:>10e}", unc_tokens), " 1.5e0 UNC");
+    }
+
+    #[test]
+    fn test_display_scientific() {
+        for (unc_tokens, expected_lower, expected_upper) in [
+            (UncToken::from_attounc(0), "0e0 UNC", "0E0 UNC"),
+            (UncToken::from_attounc(1), "1e-24 UNC", "1E-24 UNC"),
+            (UncToken::from_milliunc(1), "1e-3 UNC", "1E-3 UNC"),
+            (UncToken::from_unc(1), "1e0 UNC", "1E0 UNC"),
+            (UncToken::from_unc(1500), "1.5e3 UNC", "1.5E3 UNC"),
+            (
+                UncToken::from_attounc(u128::MAX),
+                "3.40282366920938463463374607431768211455e14 UNC",
+                "3.40282366920938463463374607431768211455E14 UNC",
+            ),
+        ] {
+            assert_eq!(format!("{:e}", unc_tokens), expected_lower);
+            assert_eq!(format!("{:E}", unc_tokens), expected_upper);
+        }
+    }
+
+    #[test]
+    fn test_display_scientific_precision() {
+        for (unc_tokens, precision, expected_display) in [
+            (UncToken::from_attounc(0), 0, "0e0 UNC"),
+            (UncToken::from_attounc(0), 2, "0.00e0 UNC"),
+            (UncToken::from_unc(1500), 0, "2e3 UNC"),
+            (UncToken::from_unc(1500), 3, "1.500e3 UNC"),
+            (UncToken::from_unc(1234), 2, "1.24e3 UNC"),
+            (UncToken::from_unc(9999), 2, "1.00e4 UNC"),
+            (UncToken::from_attounc(7), 2, "7.00e-24 UNC"),
+            (UncToken::from_attounc(u128::MAX), 3, "3.403e14 UNC"),
+        ] {
+            assert_eq!(format!("{:.*e}", precision, unc_tokens), expected_display);
+        }
+    }
+
+    #[test]
+    fn test_display_rounded() {
+        use crate::RoundingMode;
+
+        for (unc_tokens, rounding, expected_display) in [
+            (UncToken::from_attounc(0), RoundingMode::Down, "0 UNC"),
+            (UncToken::from_attounc(1), RoundingMode::Down, "<0.001 UNC"),
+            (UncToken::from_attounc(1), RoundingMode::Up, "<0.001 UNC"),
+            (
+                UncToken::from_attounc(10u128.pow(21) + 1),
+                RoundingMode::Down,
+                "0.001 UNC",
+            ),
+            (
+                UncToken::from_attounc(10u128.pow(24) - 1),
+                RoundingMode::Down,
+                "0.999 UNC",
+            ),
+            (
+                UncToken::from_attounc(10u128.pow(24) - 1),
+                RoundingMode::TowardZero,
+                "0.999 UNC",
+            ),
+            (
+                UncToken::from_attounc(10u128.pow(24) - 1),
+                RoundingMode::HalfUp,
+                "1.00 UNC",
+            ),
+            (
+                UncToken::from_milliunc(1235),
+                RoundingMode::Down,
+                "1.23 UNC",
+            ),
+            (
+                UncToken::from_milliunc(1235),
+                RoundingMode::HalfUp,
+                "1.24 UNC",
+            ),
+            (
+                UncToken::from_milliunc(1235),
+                RoundingMode::HalfEven,
+                "1.24 UNC",
+            ),
+            (
+                UncToken::from_milliunc(1245),
+                RoundingMode::HalfEven,
+                "1.24 UNC",
+            ),
+            (UncToken::from_milliunc(1245), RoundingMode::Up, "1.25 UNC"),
+        ] {
+            assert_eq!(
+                unc_tokens.display_rounded(rounding).to_string(),
+                expected_display,
+                "tokens: {}, rounding: {:?}",
+                unc_tokens.as_attounc(),
+                rounding
+            );
+        }
+        assert_eq!(
+            format!(
+                "{:>10.1}",
+                UncToken::from_milliunc(1999).display_rounded(RoundingMode::Down)
+            ),
+            "   1.9 UNC"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                UncToken::from_attounc(1).display_rounded(RoundingMode::Down)
+            ),
+            "0.000000000000000000000001 UNC"
+        );
+    }
+
+    #[test]
+    fn test_display_signed() {
+        use crate::SignedUncToken;
+
+        for (signed_unc_tokens, expected_display) in [
+            (SignedUncToken::from_attounc(0), "0 UNC"),
+            (
+                SignedUncToken::positive(UncToken::from_milliunc(1200)),
+                "+1.20 UNC",
+            ),
+            (
+                SignedUncToken::negative(UncToken::from_milliunc(500)),
+                "-0.500 UNC",
+            ),
+            (SignedUncToken::from_attounc(-1), "-<0.001 UNC"),
+        ] {
+            assert_eq!(signed_unc_tokens.to_string(), expected_display);
+        }
+        let delta = SignedUncToken::negative(UncToken::from_milliunc(3200));
+        assert_eq!(format!("{:.2}", delta), "-3.20 UNC");
+        assert_eq!(format!("{:#}", delta), "-3.2 UNC");
+        assert_eq!(format!("{:>12}", delta), "   -3.20 UNC");
+    }
+
+    #[test]
+    fn test_display_percent() {
+        use crate::{BasisPoints, Percent};
+
+        for (ppm, expected_display) in [
+            (0, "0%"),
+            (1, "0.0001%"),
+            (25_000, "2.5%"),
+            (123_400, "12.34%"),
+            (1_000_000, "100%"),
+        ] {
+            assert_eq!(
+                Percent::from_ppm(ppm).unwrap().to_string(),
+                expected_display
+            );
+        }
+        assert_eq!(BasisPoints::new(25).unwrap().to_string(), "25bps");
+    }
+
+    #[test]
+    fn test_display_token_amount() {
+        use crate::TokenAmount;
+
+        type Usdc = TokenAmount<6>;
+        for (base_units, expected_display) in [
+            (0, "0"),
+            (1, "0.000001"),
+            (2_500_000, "2.5"),
+            (1_000_000, "1"),
+            (u128::MAX, "340282366920938463463374607431768.211455"),
+        ] {
+            assert_eq!(
+                Usdc::from_base_units(base_units).to_string(),
+                expected_display
+            );
+            assert_eq!(
+                expected_display.parse::<Usdc>().unwrap().as_base_units(),
+                base_units
+            );
+        }
+        assert_eq!(format!("{:.2}", Usdc::from_base_units(1_234_567)), "1.24");
+        assert_eq!(format!("{:.0}", Usdc::from_base_units(1)), "1");
+        assert_eq!(format!("{:.8}", Usdc::from_base_units(1)), "0.00000100");
+        assert_eq!(format!("{:>6}", Usdc::from_base_units(2_500_000)), "   2.5");
+        assert_eq!(TokenAmount::<0>::from_base_units(42).to_string(), "42");
+        assert_eq!(
+            format!("{:.2}", TokenAmount::<0>::from_base_units(42)),
+            "42.00"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display_dyn_token_amount() {
+        use crate::TokenMetadata;
+
+        let usdt = TokenMetadata::new("USDT", 6).unwrap();
+        for (base_units, expected_display) in [
+            (0, "0 USDT"),
+            (999, "<0.001 USDT"),
+            (1000, "0.001 USDT"),
+            (5_000_001, "5.01 USDT"),
+            (999_999, "1.00 USDT"),
+            (12_500_000, "12.50 USDT"),
+        ] {
+            assert_eq!(usdt.amount(base_units).to_string(), expected_display);
+        }
+        assert_eq!(format!("{:#}", usdt.amount(12_500_001)), "12.500001 USDT");
+        assert_eq!(format!("{:.1}", usdt.amount(12_500_001)), "12.6 USDT");
+        assert_eq!(format!("{:>10}", usdt.amount(0)), "    0 USDT");
+
+        let cents = TokenMetadata::new("EUR", 2).unwrap();
+        for (base_units, expected_display) in
+            [(1, "0.010 EUR"), (99, "0.990 EUR"), (150, "1.50 EUR")]
+        {
+            assert_eq!(cents.amount(base_units).to_string(), expected_display);
+        }
+        let whole = TokenMetadata::new("NFT", 0).unwrap();
+        assert_eq!(whole.amount(3).to_string(), "3.00 NFT");
+        assert_eq!(format!("{:#}", whole.amount(3)), "3 NFT");
+    }
+
+    #[test]
+    fn test_display_gas() {
+        use crate::UncGas;
+
+        for (gas, expected_display) in [
+            (UncGas::from_gas(0), "0 Tgas"),
+            (UncGas::from_gas(999_999_999), "<0.001 Tgas"),
+            (UncGas::from_ggas(5), "0.005 Tgas"),
+            (UncGas::from_tgas(30), "30.00 Tgas"),
+            (UncGas::from_gas(u64::MAX), "18446744.08 Tgas"),
+        ] {
+            assert_eq!(gas.to_string(), expected_display);
+        }
+        assert_eq!(format!("{:#}", UncGas::from_ggas(1500)), "1.5 Tgas");
+        assert_eq!(format!("{:.1}", UncGas::from_ggas(1501)), "1.6 Tgas");
+        assert_eq!(format!("{:<12}|", UncGas::from_tgas(1)), "1.00 Tgas   |");
+    }
 }
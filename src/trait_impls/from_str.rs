@@ -1,30 +1,366 @@
-use crate::{UncToken, UncTokenError, ONE_UNC};
+use crate::error::ParseErrorKind;
+use crate::gas::{ONE_GIGA_GAS, ONE_TERA_GAS};
+use crate::percent::PPM_IN_ONE_HUNDRED_PERCENT;
+use crate::utils::{
+    decode_char, eq_ignore_ascii_case, is_whitespace, offset_in, parse_decimal_number, substr,
+    trim_range,
+};
+use crate::{
+    BasisPoints, ConstParseError, DecimalNumberParsingError, InputFragment, Percent, PercentError,
+    SignedUncToken, TokenAmount, UncGas, UncGasError, UncToken, UncTokenError, ONE_FEMTOUNC,
+    ONE_KILOUNC, ONE_MICROUNC, ONE_MILLIUNC, ONE_NANOUNC, ONE_PICOUNC, ONE_UNC,
+};
 
-impl std::str::FromStr for UncToken {
+/// Accepted token units with the number of atto-unc in one unit. The units of the same size are adjacent,
+/// with the full name first; the more common units come first, which is the order the `clap` value parser
+/// lists and suggests them in.
+///
+/// Units are matched ASCII case-insensitively, so `milliUNC`, `MilliUnc` and `munc` are the same unit,
+/// except for the one-letter prefixes `m` and `p`: `M` and `P` are the SI prefixes mega and peta,
+/// so `MUNC` and `PUNC` are rejected instead of being read as milli and pico.
+/// The micro prefix can be spelled as `µ` (micro sign), `μ` (greek small letter mu) or `u`.
+pub(crate) const UNITS: [(&str, u128); 19] = [
+    ("UNC", ONE_UNC),
+    ("N", ONE_UNC),
+    ("kiloUNC", ONE_KILOUNC),
+    ("kUNC", ONE_KILOUNC),
+    ("milliUNC", ONE_MILLIUNC),
+    ("mUNC", ONE_MILLIUNC),
+    ("microUNC", ONE_MICROUNC),
+    ("\u{b5}UNC", ONE_MICROUNC),
+    ("\u{3bc}UNC", ONE_MICROUNC),
+    ("uUNC", ONE_MICROUNC),
+    ("nanoUNC", ONE_NANOUNC),
+    ("nUNC", ONE_NANOUNC),
+    ("picoUNC", ONE_PICOUNC),
+    ("pUNC", ONE_PICOUNC),
+    ("femtoUNC", ONE_FEMTOUNC),
+    ("fUNC", ONE_FEMTOUNC),
+    ("attoUNC", 1),
+    ("aUNC", 1),
+    ("aN", 1),
+];
+
+/// Accepted gas units (upper-cased) with the number of gas units in one unit.
+const GAS_UNITS: [(&str, u128); 5] = [
+    ("TERAGAS", ONE_TERA_GAS as u128),
+    ("TGAS", ONE_TERA_GAS as u128),
+    ("GIGAGAS", ONE_GIGA_GAS as u128),
+    ("GGAS", ONE_GIGA_GAS as u128),
+    ("GAS", 1),
+];
+
+/// Returns the precision exponent of `unit` (there are `10^exponent` base units in one `unit`),
+/// if `unit` is one of the accepted `units`.
+const fn unit_precision(units: &[(&str, u128)], unit: &str) -> Option<u32> {
+    let mut index = 0;
+    while index < units.len() {
+        let (name, base_units) = units[index];
+        if eq_ignore_ascii_case(name, unit) && !is_mega_or_peta(name, unit) {
+            return Some(base_units.ilog10());
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns true if `unit` spells the one-letter prefix `m` or `p` of the unit `name` as `M` or `P`,
+/// which are the SI prefixes mega and peta rather than milli and pico.
+/// `unit` must be equal to `name` ignoring ASCII case.
+const fn is_mega_or_peta(name: &str, unit: &str) -> bool {
+    let (name, unit) = (name.as_bytes(), unit.as_bytes());
+    name.len() == "mUNC".len() && matches!(unit[0], b'M' | b'P') && name[0] != unit[0]
+}
+
+/// The units accepted by [`parse_with_unit`].
+#[derive(Clone, Copy)]
+enum Units<'a> {
+    /// One of the units of the table, which starts with the first letter after the number.
+    Table(&'a [(&'a str, u128)]),
+    /// A token symbol with the number of decimals of the token. The symbol is matched at the end
+    /// of the input, so symbols that do not start with a letter, like `1INCH`, are supported too.
+    #[cfg(feature = "alloc")]
+    Symbol(&'a str, u32),
+}
+
+/// Returns the start of the unit in the trimmed `s[start..end]`: the first letter, where any non-ASCII
+/// character other than whitespace counts as a letter, or `end` if there is none.
+const fn find_unit_start(s: &str, start: usize, end: usize) -> usize {
+    let bytes = s.as_bytes();
+    let mut unit_start = start;
+    while unit_start < end {
+        let (c, len) = decode_char(bytes, unit_start);
+        let letter = if c < 0x80 {
+            (c as u8).is_ascii_alphabetic()
+        } else {
+            !is_whitespace(c)
+        };
+        if letter {
+            break;
+        }
+        unit_start += len;
+    }
+    unit_start
+}
+
+/// Parses a decimal number followed by one of the `units` into a number of base units:
+/// a decimal number, optional whitespace and the unit.
+const fn parse_with_unit(s: &str, units: Units<'_>) -> Result<u128, ConstParseError> {
+    let (start, end) = trim_range(s, 0, s.len());
+    let letter_start = find_unit_start(s, start, end);
+    let (unit_start, unit_precision) = match units {
+        Units::Table(table) => match unit_precision(table, substr(s, letter_start, end)) {
+            Some(unit_precision) => (letter_start, Some(unit_precision)),
+            None => (letter_start, None),
+        },
+        #[cfg(feature = "alloc")]
+        Units::Symbol(symbol, decimals) => {
+            let matches = end >= start + symbol.len() && {
+                let unit_start = end - symbol.len();
+                // The symbol must not start in the middle of a multi-byte character.
+                (unit_start == end || !matches!(s.as_bytes()[unit_start], 0x80..=0xbf))
+                    && eq_ignore_ascii_case(substr(s, unit_start, end), symbol)
+            };
+            if matches {
+                (end - symbol.len(), Some(decimals))
+            } else {
+                (letter_start, None)
+            }
+        }
+    };
+    let unit_precision = match unit_precision {
+        Some(unit_precision) => unit_precision,
+        // Point at the word that was meant to be the unit, or right after the number if there is none.
+        None => {
+            return Err(ConstParseError::new(
+                ParseErrorKind::InvalidUnit,
+                0..s.len(),
+                unit_start..end,
+            ))
+        }
+    };
+    let (value_start, value_end) = trim_range(s, start, unit_start);
+    match parse_decimal_number(substr(s, value_start, value_end), unit_precision) {
+        Ok(base_units) => Ok(base_units),
+        Err(err) => Err(err.shifted(value_start)),
+    }
+}
+
+impl UncToken {
+    /// `parse_const` is a function that parses a token amount with a unit, e.g. `1.25 UNC` or `500 milliUNC`,
+    /// and can be used in const contexts. It accepts the same grammar as `UncToken::from_str`:
+    /// a decimal number, optional whitespace and a unit that starts with the first letter
+    /// (any non-ASCII character other than whitespace counts as a letter).
+    /// Being `const`, it returns a [`ConstParseError`], which can be turned into
+    /// the error of `UncToken::from_str` with [`to_token_error`](crate::ConstParseError::to_token_error).
+    ///
+    /// See the [`unc!`](crate::unc) macro for the compile-time checked literals.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::UncToken;
+    /// const DEPOSIT: UncToken = match UncToken::parse_const("1.25 UNC") {
+    ///     Ok(deposit) => deposit,
+    ///     Err(_) => panic!("invalid deposit"),
+    /// };
+    /// assert_eq!(DEPOSIT, UncToken::from_milliunc(1250));
+    /// assert!(UncToken::parse_const("1.25 UAH").is_err());
+    /// ```
+    pub const fn parse_const(s: &str) -> Result<Self, ConstParseError> {
+        match parse_with_unit(s, Units::Table(&UNITS)) {
+            Ok(attounc) => Ok(UncToken::from_attounc(attounc)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl core::str::FromStr for UncToken {
+    type Err = UncTokenError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with_unit(s, Units::Table(&UNITS))
+            .map(UncToken::from_attounc)
+            .map_err(|err| err.to_token_error())
+    }
+}
+
+impl<const DECIMALS: u8> TokenAmount<DECIMALS> {
+    /// `parse_const` is a function that parses a decimal number of whole tokens without a unit, e.g. `2.5`,
+    /// with up to `DECIMALS` digits after the floating point, and can be used in const contexts.
+    /// Surrounding whitespace is ignored, like `TokenAmount::from_str` does. Being `const`, it returns
+    /// a [`ConstParseError`] instead of the error of `from_str`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::TokenAmount;
+    /// const PRICE: TokenAmount<6> = match TokenAmount::parse_const("2.5") {
+    ///     Ok(price) => price,
+    ///     Err(_) => panic!("invalid price"),
+    /// };
+    /// assert_eq!(PRICE, TokenAmount::from_base_units(2_500_000));
+    /// assert!(TokenAmount::<6>::parse_const("0.0000001").is_err());
+    /// ```
+    pub const fn parse_const(s: &str) -> Result<Self, ConstParseError> {
+        match parse_decimal_number(s, DECIMALS as u32) {
+            Ok(base_units) => Ok(TokenAmount::from_base_units(base_units)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<const DECIMALS: u8> core::str::FromStr for TokenAmount<DECIMALS> {
+    type Err = DecimalNumberParsingError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_decimal_number(s, DECIMALS as u32)
+            .map(TokenAmount::from_base_units)
+            .map_err(|err| err.to_decimal_error())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl crate::TokenMetadata {
+    /// `parse_amount` is a function that parses an amount of the token with its symbol, e.g. `12.5 USDT`.
+    ///
+    /// The grammar follows `UncToken::from_str`: a decimal number with up to `decimals` digits after
+    /// the floating point, optional whitespace and the symbol, which is matched ASCII case-insensitively.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_token::{TokenMetadata, UncTokenError};
+    /// let usdt = TokenMetadata::new("USDT", 6).unwrap();
+    /// assert_eq!(usdt.parse_amount("12.5 usdt").unwrap().as_base_units(), 12_500_000);
+    /// assert!(matches!(usdt.parse_amount("12.5 USDC"), Err(UncTokenError::InvalidTokenUnit(_))));
+    /// ```
+    pub fn parse_amount(&self, s: &str) -> Result<crate::DynTokenAmount<'_>, UncTokenError> {
+        parse_with_unit(s, Units::Symbol(self.symbol(), self.decimals().into()))
+            .map(|base_units| self.amount(base_units))
+            .map_err(|err| err.to_token_error())
+    }
+}
+
+/// UncGas is parsed from a decimal number followed by a gas unit, e.g. `30 Tgas`, `1.5 Ggas` or `100 gas`.
+/// Units are matched ASCII case-insensitively and can be spelled out, e.g. `TeraGas` or `gigagas`.
+impl core::str::FromStr for UncGas {
+    type Err = UncGasError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let gas = parse_with_unit(s, Units::Table(&GAS_UNITS)).map_err(|err| {
+            match err.to_token_error() {
+                UncTokenError::InvalidTokensAmount(err) => UncGasError::InvalidGasAmount(err),
+                UncTokenError::InvalidTokenUnit(unit) => UncGasError::InvalidGasUnit(unit),
+            }
+        })?;
+        u64::try_from(gas).map(UncGas::from_gas).map_err(|_| {
+            // The number parsed fine, so it is everything before the unit; point at its whole part.
+            let (start, end) = trim_range(s, 0, s.len());
+            let (value_start, value_end) = trim_range(s, start, find_unit_start(s, start, end));
+            let value = substr(s, value_start, value_end);
+            let whole = value.split('.').next().unwrap_or(value);
+            UncGasError::InvalidGasAmount(DecimalNumberParsingError::LongWhole(InputFragment::new(
+                value_start..value_start + whole.len(),
+                value_start..value_start + whole.len(),
+            )))
+        })
+    }
+}
+
+/// Returns the fragment that quotes the whole input and points right after its `trimmed` part,
+/// where the unit is expected.
+fn missing_unit(s: &str, trimmed: &str) -> InputFragment {
+    let end = offset_in(s, trimmed) + trimmed.len();
+    InputFragment::new(0..s.len(), end..end)
+}
+
+/// SignedUncToken accepts the same format as `UncToken`, optionally prefixed with a single `+` or `-` sign.
+impl core::str::FromStr for SignedUncToken {
     type Err = UncTokenError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let uppercase_s = s.trim().to_ascii_uppercase();
-        let (value, unit) = uppercase_s.split_at(
-            s.find(|c: char| c.is_ascii_alphabetic())
-                .ok_or_else(|| UncTokenError::InvalidTokenUnit(s.to_owned()))?,
-        );
-        let unit_precision = match unit {
-            "AN" | "AUNC" | "ATTOUNC" => 1,
-            "UNC" | "N" => ONE_UNC,
-            _ => return Err(UncTokenError::InvalidTokenUnit(s.to_owned())),
+        let trimmed = s.trim_start();
+        let (negative, magnitude) = match trimmed.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
         };
-        Ok(UncToken::from_attounc(
-            crate::utils::parse_decimal_number(value.trim(), unit_precision)
-                .map_err(UncTokenError::InvalidTokensAmount)?,
-        ))
+        // `UncToken::from_str` accepts a `+` of its own, which must not follow the sign.
+        if magnitude.len() < trimmed.len() && magnitude.starts_with('+') {
+            let start = offset_in(s, magnitude);
+            return Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::InvalidNumber(InputFragment::new(
+                    0..s.len(),
+                    start..start + 1,
+                )),
+            ));
+        }
+        let magnitude: UncToken = magnitude
+            .parse()
+            .map_err(|err: UncTokenError| err.shifted(offset_in(s, magnitude)))?;
+        Ok(if negative {
+            SignedUncToken::negative(magnitude)
+        } else {
+            SignedUncToken::positive(magnitude)
+        })
+    }
+}
+
+/// Percent is parsed from a decimal number followed by a `%` sign, e.g. `2.5%` or `2.5 %`.
+impl core::str::FromStr for Percent {
+    type Err = PercentError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let value = trimmed
+            .strip_suffix('%')
+            .ok_or_else(|| PercentError::InvalidUnit(missing_unit(s, trimmed)))?
+            .trim();
+        let ppm = crate::utils::parse_decimal(value, (PPM_IN_ONE_HUNDRED_PERCENT / 100).ilog10())
+            .map_err(|err| PercentError::InvalidNumber(err.shifted(offset_in(s, value))))?;
+        u32::try_from(ppm)
+            .ok()
+            .and_then(Percent::from_ppm)
+            .ok_or_else(|| PercentError::OutOfRange(value_fragment(s, value)))
+    }
+}
+
+/// BasisPoints is parsed from a whole number followed by `bps`, e.g. `25bps` or `25 bps`.
+impl core::str::FromStr for BasisPoints {
+    type Err = PercentError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let value = trimmed
+            .len()
+            .checked_sub(3)
+            .filter(|unit_start| trimmed.is_char_boundary(*unit_start))
+            .map(|unit_start| trimmed.split_at(unit_start))
+            .filter(|(_, unit)| unit.eq_ignore_ascii_case("bps"))
+            .map(|(value, _)| value.trim())
+            .ok_or_else(|| {
+                // Point at the trailing word that was meant to be the unit, if there is one.
+                let end = offset_in(s, trimmed) + trimmed.len();
+                let unit_len = trimmed.len() - trimmed.trim_end_matches(char::is_alphabetic).len();
+                PercentError::InvalidUnit(InputFragment::new(0..s.len(), end - unit_len..end))
+            })?;
+        let bps = crate::utils::parse_decimal(value, 0)
+            .map_err(|err| PercentError::InvalidNumber(err.shifted(offset_in(s, value))))?;
+        u16::try_from(bps)
+            .ok()
+            .and_then(BasisPoints::new)
+            .ok_or_else(|| PercentError::OutOfRange(value_fragment(s, value)))
     }
 }
 
+/// Returns the fragment that quotes the whole input and points at its numeric `value` part.
+fn value_fragment(s: &str, value: &str) -> InputFragment {
+    let start = offset_in(s, value);
+    InputFragment::new(0..s.len(), start..start + value.len())
+}
+
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
 
-    use crate::{DecimalNumberParsingError, UncToken, UncTokenError};
+    use crate::{DecimalNumberParsingError, InputFragment, UncToken, UncTokenError};
+
+    /// A fragment that quotes the first occurrence of `quote` in `input` and points at `span`.
+    fn fragment(input: &str, quote: &str, span: core::ops::Range<usize>) -> InputFragment {
+        let start = input.find(quote).unwrap();
+        InputFragment::new(start..start + quote.len(), span)
+    }
 
     #[test]
     fn parse_decimal_number() {
@@ -59,7 +395,7 @@ mod test {
         assert_eq!(
             gas,
             Err(UncTokenError::InvalidTokensAmount(
-                DecimalNumberParsingError::InvalidNumber("1.1.1".to_owned())
+                DecimalNumberParsingError::InvalidNumber(fragment(data, "1.1.1", 3..4))
             ))
         )
     }
@@ -71,7 +407,7 @@ mod test {
         assert_eq!(
             gas,
             Err(UncTokenError::InvalidTokensAmount(
-                DecimalNumberParsingError::InvalidNumber("1. 0".to_owned())
+                DecimalNumberParsingError::InvalidNumber(fragment(data, "1. 0", 2..3))
             ))
         )
     }
@@ -80,14 +416,20 @@ mod test {
     fn incorect_currency() {
         let data = "0 pas";
         let gas: Result<UncToken, UncTokenError> = FromStr::from_str(data);
-        assert_eq!(gas, Err(UncTokenError::InvalidTokenUnit(data.to_owned())))
+        assert_eq!(
+            gas,
+            Err(UncTokenError::InvalidTokenUnit(fragment(data, data, 2..5)))
+        )
     }
 
     #[test]
     fn without_currency() {
         let data = "0";
         let gas: Result<UncToken, UncTokenError> = FromStr::from_str(data);
-        assert_eq!(gas, Err(UncTokenError::InvalidTokenUnit("0".to_owned())))
+        assert_eq!(
+            gas,
+            Err(UncTokenError::InvalidTokenUnit(fragment(data, "0", 1..1)))
+        )
     }
 
     #[test]
@@ -97,19 +439,36 @@ mod test {
         assert_eq!(
             gas,
             Err(UncTokenError::InvalidTokensAmount(
-                DecimalNumberParsingError::InvalidNumber("-1".to_owned())
+                DecimalNumberParsingError::InvalidNumber(fragment(data, "-1", 0..1))
             ))
         )
     }
 
+    #[test]
+    fn sign_in_fractional_part() {
+        let data = "1.+5 UNC";
+        assert_eq!(
+            UncToken::from_str(data),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::InvalidNumber(fragment(data, "1.+5", 2..3))
+            ))
+        );
+        assert_eq!(
+            UncToken::from_str("+1.5 UNC"),
+            Ok(UncToken::from_milliunc(1500))
+        );
+    }
+
     #[test]
     fn test_from_str_f64_gas_without_int() {
-        let unc_gas = UncToken::from_str(".055 yunc").unwrap_err();
+        let unc_gas = UncToken::from_str(".055 aunc").unwrap_err();
         assert_eq!(
             unc_gas,
-            UncTokenError::InvalidTokensAmount(DecimalNumberParsingError::InvalidNumber(
-                ".055".to_string()
-            ))
+            UncTokenError::InvalidTokensAmount(DecimalNumberParsingError::InvalidNumber(fragment(
+                ".055 aunc",
+                ".055",
+                0..4
+            )))
         );
     }
 
@@ -118,7 +477,7 @@ mod test {
         let unc_gas = UncToken::from_str("100").unwrap_err();
         assert_eq!(
             unc_gas,
-            UncTokenError::InvalidTokenUnit("100".to_string())
+            UncTokenError::InvalidTokenUnit(fragment("100", "100", 3..3))
         );
     }
 
@@ -127,7 +486,7 @@ mod test {
         let unc_gas = UncToken::from_str("100 UAH").unwrap_err();
         assert_eq!(
             unc_gas,
-            UncTokenError::InvalidTokenUnit("100 UAH".to_string())
+            UncTokenError::InvalidTokenUnit(fragment("100 UAH", "100 UAH", 4..7))
         );
     }
 
@@ -136,7 +495,7 @@ mod test {
         let unc_gas = UncToken::from_str("100.55.").unwrap_err();
         assert_eq!(
             unc_gas,
-            UncTokenError::InvalidTokenUnit("100.55.".to_string())
+            UncTokenError::InvalidTokenUnit(fragment("100.55.", "100.55.", 7..7))
         );
     }
 
@@ -146,7 +505,394 @@ mod test {
         assert_eq!(
             unc_gas,
             UncTokenError::InvalidTokensAmount(DecimalNumberParsingError::LongFractional(
-                "1111122222333".to_string()
+                fragment("100.1111122222333 aunc", "1111122222333", 4..17)
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_si_units() {
+        for (data, expected) in [
+            ("2 kUNC", UncToken::from_kilounc(2)),
+            ("1.5 kilounc", UncToken::from_unc(1500)),
+            ("250 milliUNC", UncToken::from_milliunc(250)),
+            ("250 mUNC", UncToken::from_milliunc(250)),
+            ("3 \u{b5}UNC", UncToken::from_microunc(3)),
+            ("3 \u{3bc}UNC", UncToken::from_microunc(3)),
+            ("3 uUNC", UncToken::from_microunc(3)),
+            ("3 microUNC", UncToken::from_microunc(3)),
+            ("12 nanoUNC", UncToken::from_nanounc(12)),
+            ("12 nUNC", UncToken::from_nanounc(12)),
+            ("7 picoUNC", UncToken::from_picounc(7)),
+            ("7 pUNC", UncToken::from_picounc(7)),
+            ("9 femtoUNC", UncToken::from_femtounc(9)),
+            ("9 fUNC", UncToken::from_femtounc(9)),
+            ("5 attoUNC", UncToken::from_attounc(5)),
+            ("5 aUNC", UncToken::from_attounc(5)),
+            ("1 N", UncToken::from_unc(1)),
+            ("  0.001 UNC  ", UncToken::from_milliunc(1)),
+        ] {
+            assert_eq!(UncToken::from_str(data), Ok(expected), "input: {}", data);
+        }
+    }
+
+    #[test]
+    fn parse_mega_and_peta_prefixes() {
+        assert_eq!(UncToken::from_str("1 munc"), Ok(UncToken::from_milliunc(1)));
+        assert_eq!(
+            UncToken::from_str("1 MilliUnc"),
+            Ok(UncToken::from_milliunc(1))
+        );
+        for data in ["1 MUNC", "1 Munc", "1 PUNC"] {
+            assert_eq!(
+                UncToken::from_str(data),
+                Err(UncTokenError::InvalidTokenUnit(fragment(data, data, 2..6))),
+                "input: {}",
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn parse_unicode_whitespace_before_unit() {
+        use crate::{SignedUncToken, UncGas};
+
+        // A non-breaking space, as inserted between a number and its unit by some formatters.
+        assert_eq!(
+            UncToken::from_str("1.5\u{a0}UNC"),
+            Ok(UncToken::from_milliunc(1500))
+        );
+        assert_eq!(
+            UncToken::from_str("2\u{202f}mUNC"),
+            Ok(UncToken::from_milliunc(2))
+        );
+        assert_eq!(
+            UncToken::parse_const("1.5\u{a0}UNC"),
+            Ok(UncToken::from_milliunc(1500))
+        );
+        assert_eq!(
+            SignedUncToken::from_str("-1.5\u{a0}UNC"),
+            Ok(SignedUncToken::negative(UncToken::from_milliunc(1500)))
+        );
+        assert_eq!(UncGas::from_str("30\u{a0}Tgas"), Ok(UncGas::from_tgas(30)));
+        // Other non-ASCII characters still start the unit.
+        assert_eq!(
+            UncToken::from_str("1.5\u{a0}\u{b5}UNCS"),
+            Err(UncTokenError::InvalidTokenUnit(fragment(
+                "1.5\u{a0}\u{b5}UNCS",
+                "1.5\u{a0}\u{b5}UNCS",
+                5..11
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_too_precise_sub_unit() {
+        let unc_gas = UncToken::from_str("1.0000000001 femtoUNC").unwrap_err();
+        assert_eq!(
+            unc_gas,
+            UncTokenError::InvalidTokensAmount(DecimalNumberParsingError::LongFractional(
+                fragment("1.0000000001 femtoUNC", "0000000001", 2..12)
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_signed() {
+        use crate::SignedUncToken;
+
+        for (data, expected) in [
+            ("-1 UNC", SignedUncToken::negative(UncToken::from_unc(1))),
+            (
+                " -3.2 UNC",
+                SignedUncToken::negative(UncToken::from_milliunc(3200)),
+            ),
+            (
+                "+1.20 UNC",
+                SignedUncToken::positive(UncToken::from_milliunc(1200)),
+            ),
+            (
+                "1.20 UNC",
+                SignedUncToken::positive(UncToken::from_milliunc(1200)),
+            ),
+            ("-0 UNC", SignedUncToken::from_attounc(0)),
+            ("-5 aUNC", SignedUncToken::from_attounc(-5)),
+        ] {
+            assert_eq!(
+                SignedUncToken::from_str(data),
+                Ok(expected),
+                "input: {}",
+                data
+            );
+        }
+        assert_eq!(
+            SignedUncToken::from_str("--1 UNC"),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::InvalidNumber(fragment("--1 UNC", "-1", 1..2))
+            ))
+        );
+        for data in ["-+1 UNC", "++1 UNC"] {
+            assert_eq!(
+                SignedUncToken::from_str(data),
+                Err(UncTokenError::InvalidTokensAmount(
+                    DecimalNumberParsingError::InvalidNumber(fragment(data, data, 1..2))
+                )),
+                "input: {}",
+                data
+            );
+        }
+        assert_eq!(
+            SignedUncToken::from_str("+-1 UNC"),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::InvalidNumber(fragment("+-1 UNC", "-1", 1..2))
+            ))
+        );
+        assert_eq!(
+            SignedUncToken::from_str("-1"),
+            Err(UncTokenError::InvalidTokenUnit(fragment("-1", "1", 2..2)))
+        );
+    }
+
+    #[test]
+    fn parse_percent() {
+        use crate::{Percent, PercentError};
+
+        for (data, expected_ppm) in [
+            ("2.5%", 25_000),
+            (" 2.5 % ", 25_000),
+            ("0%", 0),
+            ("100%", 1_000_000),
+            ("100.0000%", 1_000_000),
+            ("0.0001%", 1),
+        ] {
+            assert_eq!(
+                Percent::from_str(data),
+                Ok(Percent::from_ppm(expected_ppm).unwrap()),
+                "input: {}",
+                data
+            );
+        }
+        assert_eq!(
+            Percent::from_str("2.5"),
+            Err(PercentError::InvalidUnit(fragment("2.5", "2.5", 3..3)))
+        );
+        assert_eq!(
+            Percent::from_str("100.0001%"),
+            Err(PercentError::OutOfRange(fragment(
+                "100.0001%",
+                "100.0001%",
+                0..8
+            )))
+        );
+        assert_eq!(
+            Percent::from_str("-1%"),
+            Err(PercentError::InvalidNumber(
+                DecimalNumberParsingError::InvalidNumber(fragment("-1%", "-1", 0..1))
+            ))
+        );
+        assert_eq!(
+            Percent::from_str("0.00001%"),
+            Err(PercentError::InvalidNumber(
+                DecimalNumberParsingError::LongFractional(fragment("0.00001%", "00001", 2..7))
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_basis_points() {
+        use crate::{BasisPoints, PercentError};
+
+        for (data, expected) in [
+            ("25bps", 25),
+            (" 25 BPS ", 25),
+            ("0bps", 0),
+            ("10000bps", 10_000),
+        ] {
+            assert_eq!(
+                BasisPoints::from_str(data),
+                Ok(BasisPoints::new(expected).unwrap()),
+                "input: {}",
+                data
+            );
+        }
+        assert_eq!(
+            BasisPoints::from_str("25"),
+            Err(PercentError::InvalidUnit(fragment("25", "25", 2..2)))
+        );
+        assert_eq!(
+            BasisPoints::from_str("10001bps"),
+            Err(PercentError::OutOfRange(fragment(
+                "10001bps",
+                "10001bps",
+                0..5
+            )))
+        );
+        assert_eq!(
+            BasisPoints::from_str("99999999999bps"),
+            Err(PercentError::OutOfRange(fragment(
+                "99999999999bps",
+                "99999999999bps",
+                0..11
+            )))
+        );
+        assert_eq!(
+            BasisPoints::from_str("2.5bps"),
+            Err(PercentError::InvalidNumber(
+                DecimalNumberParsingError::LongFractional(fragment("2.5bps", "5", 2..3))
+            ))
+        );
+        assert_eq!(
+            BasisPoints::from_str("µbps"),
+            Err(PercentError::InvalidNumber(
+                DecimalNumberParsingError::InvalidNumber(fragment("µbps", "µ", 0..2))
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_token_amount() {
+        use crate::TokenAmount;
+
+        type Wbtc = TokenAmount<8>;
+        assert_eq!(
+            Wbtc::from_str(" 0.5 "),
+            Ok(Wbtc::from_base_units(50_000_000))
+        );
+        assert_eq!(Wbtc::from_str("21"), Ok(Wbtc::from_whole(21)));
+        assert_eq!(
+            TokenAmount::<18>::from_str("1.000000000000000001"),
+            Ok(TokenAmount::from_base_units(10u128.pow(18) + 1))
+        );
+        assert_eq!(
+            TokenAmount::<0>::from_str("42"),
+            Ok(TokenAmount::from_whole(42))
+        );
+        assert_eq!(
+            Wbtc::from_str(" 0.000000001"),
+            Err(DecimalNumberParsingError::LongFractional(fragment(
+                " 0.000000001",
+                "000000001",
+                3..12
+            )))
+        );
+        assert_eq!(
+            Wbtc::from_str("1 BTC"),
+            Err(DecimalNumberParsingError::InvalidNumber(fragment(
+                "1 BTC",
+                "1 BTC",
+                1..2
+            )))
+        );
+        assert_eq!(
+            TokenAmount::<38>::from_str("4"),
+            Err(DecimalNumberParsingError::LongWhole(fragment(
+                "4",
+                "4",
+                0..1
+            )))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn parse_dyn_token_amount() {
+        use crate::TokenMetadata;
+
+        let usdt = TokenMetadata::new("USDT", 6).unwrap();
+        for (data, expected) in [
+            ("12.5 USDT", 12_500_000),
+            ("  0.000001usdt ", 1),
+            ("7 UsDt", 7_000_000),
+        ] {
+            assert_eq!(
+                usdt.parse_amount(data).map(|amount| amount.as_base_units()),
+                Ok(expected),
+                "input: {}",
+                data
+            );
+        }
+        assert_eq!(
+            usdt.parse_amount("12.5 USDC"),
+            Err(UncTokenError::InvalidTokenUnit(fragment(
+                "12.5 USDC",
+                "12.5 USDC",
+                5..9
+            )))
+        );
+        assert_eq!(
+            usdt.parse_amount("12.5"),
+            Err(UncTokenError::InvalidTokenUnit(fragment(
+                "12.5",
+                "12.5",
+                4..4
+            )))
+        );
+        assert_eq!(
+            usdt.parse_amount("0.0000001 USDT"),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::LongFractional(fragment(
+                    "0.0000001 USDT",
+                    "0000001",
+                    2..9
+                ))
+            ))
+        );
+
+        let inch = TokenMetadata::new("1INCH", 18).unwrap();
+        assert_eq!(
+            inch.parse_amount("2.5 1inch")
+                .map(|amount| amount.as_base_units()),
+            Ok(25 * 10u128.pow(17))
+        );
+    }
+
+    #[test]
+    fn parse_gas() {
+        use crate::{UncGas, UncGasError};
+
+        for (data, expected) in [
+            ("30 Tgas", UncGas::from_tgas(30)),
+            ("30 TGas", UncGas::from_tgas(30)),
+            ("1.5 teragas", UncGas::from_ggas(1500)),
+            ("2.5Ggas", UncGas::from_gas(2_500_000_000)),
+            ("7 GigaGas", UncGas::from_ggas(7)),
+            (" 100 gas ", UncGas::from_gas(100)),
+            ("18446744073709551615 gas", UncGas::from_gas(u64::MAX)),
+        ] {
+            assert_eq!(UncGas::from_str(data), Ok(expected), "input: {}", data);
+        }
+        assert_eq!(
+            UncGas::from_str("30 Pgas"),
+            Err(UncGasError::InvalidGasUnit(fragment(
+                "30 Pgas",
+                "30 Pgas",
+                3..7
+            )))
+        );
+        assert_eq!(
+            UncGas::from_str("30"),
+            Err(UncGasError::InvalidGasUnit(fragment("30", "30", 2..2)))
+        );
+        assert_eq!(
+            UncGas::from_str("1.5 gas"),
+            Err(UncGasError::InvalidGasAmount(
+                DecimalNumberParsingError::LongFractional(fragment("1.5 gas", "5", 2..3))
+            ))
+        );
+        assert_eq!(
+            UncGas::from_str(" 18446744073709551616 gas"),
+            Err(UncGasError::InvalidGasAmount(
+                DecimalNumberParsingError::LongWhole(fragment(
+                    " 18446744073709551616 gas",
+                    "18446744073709551616",
+                    1..21
+                ))
+            ))
+        );
+        assert_eq!(
+            UncGas::from_str("18446744.5 Tgas"),
+            Err(UncGasError::InvalidGasAmount(
+                DecimalNumberParsingError::LongWhole(fragment("18446744.5 Tgas", "18446744", 0..8))
             ))
         );
     }
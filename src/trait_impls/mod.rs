@@ -1,10 +1,31 @@
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "borsh")]
 mod borsh;
-mod display;
-mod from_str;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(any(feature = "diesel-postgres", feature = "diesel-sqlite"))]
+mod diesel;
+pub(crate) mod display;
+pub(crate) mod from_str;
 #[cfg(feature = "interactive-clap")]
 mod interactive_clap;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod ops;
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "diesel-postgres",
+    feature = "postgres-types"
+))]
+mod pg_numeric;
+#[cfg(feature = "postgres-types")]
+mod postgres_types;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
 #[cfg(feature = "schemars")]
 mod schemars;
 #[cfg(feature = "serde")]
-mod serde;
+pub(crate) mod serde;
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+mod sqlx;
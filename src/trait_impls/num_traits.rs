@@ -0,0 +1,139 @@
+use num_traits::{Bounded, CheckedAdd, CheckedSub, SaturatingAdd, SaturatingSub, Zero};
+
+use crate::{StorageUsage, TokenAmount, UncGas, UncToken};
+
+/// Implements the `num_traits` traits that apply to an amount type `$ty` that is created with `$from`
+/// from its raw `$int` value, generic over the parameters in the brackets.
+///
+/// `CheckedMul`, `CheckedDiv`, `SaturatingMul`, `One` and `Num` are not implemented: they require multiplying
+/// and dividing two amounts, which does not result in an amount (see the `num-traits` feature docs).
+macro_rules! impl_num_traits {
+    ([$($generics:tt)*] $ty:ty, $int:ty, $from:ident) => {
+        impl<$($generics)*> Zero for $ty {
+            fn zero() -> Self {
+                <$ty>::$from(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                <$ty>::is_zero(self)
+            }
+        }
+
+        impl<$($generics)*> Bounded for $ty {
+            fn min_value() -> Self {
+                <$ty>::$from(<$int>::MIN)
+            }
+
+            fn max_value() -> Self {
+                <$ty>::$from(<$int>::MAX)
+            }
+        }
+
+        impl<$($generics)*> CheckedAdd for $ty {
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$ty>::checked_add(*self, *rhs)
+            }
+        }
+
+        impl<$($generics)*> CheckedSub for $ty {
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$ty>::checked_sub(*self, *rhs)
+            }
+        }
+
+        impl<$($generics)*> SaturatingAdd for $ty {
+            fn saturating_add(&self, rhs: &Self) -> Self {
+                <$ty>::saturating_add(*self, *rhs)
+            }
+        }
+
+        impl<$($generics)*> SaturatingSub for $ty {
+            fn saturating_sub(&self, rhs: &Self) -> Self {
+                <$ty>::saturating_sub(*self, *rhs)
+            }
+        }
+    };
+}
+
+impl_num_traits!([] UncToken, u128, from_attounc);
+impl_num_traits!([const DECIMALS: u8] TokenAmount<DECIMALS>, u128, from_base_units);
+impl_num_traits!([] UncGas, u64, from_gas);
+impl_num_traits!([] StorageUsage, u64, from_bytes);
+
+#[cfg(test)]
+mod test {
+    use num_traits::{Bounded, CheckedAdd, CheckedSub, SaturatingAdd, SaturatingSub, Zero};
+
+    use crate::{StorageUsage, TokenAmount, UncGas, UncToken};
+
+    /// Generic code that is only bounded on `num_traits` traits.
+    fn checked_total<T: Zero + CheckedAdd>(values: &[T]) -> Option<T> {
+        values
+            .iter()
+            .try_fold(T::zero(), |total, value| total.checked_add(value))
+    }
+
+    fn spread<T: Bounded + PartialOrd + Copy + CheckedSub>(values: &[T]) -> Option<T> {
+        let (mut min, mut max) = (T::max_value(), T::min_value());
+        for &value in values {
+            if value < min {
+                min = value;
+            }
+            if value > max {
+                max = value;
+            }
+        }
+        max.checked_sub(&min)
+    }
+
+    #[test]
+    fn unc_token_num_traits() {
+        let amounts = [
+            UncToken::from_unc(1),
+            UncToken::from_unc(3),
+            UncToken::from_milliunc(500),
+        ];
+        assert_eq!(
+            checked_total(&amounts),
+            Some(UncToken::from_milliunc(4_500))
+        );
+        assert_eq!(
+            checked_total(&[UncToken::MAX, UncToken::from_attounc(1)]),
+            None
+        );
+        assert_eq!(spread(&amounts), Some(UncToken::from_milliunc(2_500)));
+        assert_eq!(<UncToken as Bounded>::min_value(), UncToken::MIN);
+        assert_eq!(<UncToken as Bounded>::max_value(), UncToken::MAX);
+        assert!(Zero::is_zero(&UncToken::zero()));
+        assert_eq!(
+            SaturatingAdd::saturating_add(&UncToken::MAX, &UncToken::from_unc(1)),
+            UncToken::MAX
+        );
+        assert_eq!(
+            SaturatingSub::saturating_sub(&UncToken::MIN, &UncToken::from_unc(1)),
+            UncToken::MIN
+        );
+        assert_eq!(
+            CheckedSub::checked_sub(&UncToken::MIN, &UncToken::from_attounc(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn other_amounts_num_traits() {
+        assert_eq!(
+            checked_total(&[TokenAmount::<6>::ONE, TokenAmount::<6>::ONE]),
+            Some(TokenAmount::<6>::from_whole(2))
+        );
+        assert_eq!(<TokenAmount<6> as Bounded>::max_value(), TokenAmount::MAX);
+        assert_eq!(
+            checked_total(&[UncGas::from_tgas(1), UncGas::from_tgas(2)]),
+            Some(UncGas::from_tgas(3))
+        );
+        assert_eq!(<UncGas as Bounded>::max_value(), UncGas::from_gas(u64::MAX));
+        assert_eq!(
+            spread(&[StorageUsage::from_bytes(10), StorageUsage::from_bytes(4)]),
+            Some(StorageUsage::from_bytes(6))
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::{GasPrice, StorageUsage, TokenAmount, UncGas, UncToken};
+
+/// Implements the arithmetic operators for an amount type `$ty` that is created with `$from`
+/// and exposes its raw `$int` value with `$as`, generic over the parameters in the brackets.
+macro_rules! impl_ops {
+    ([$($generics:tt)*] $ty:ty, $int:ty, $from:ident, $as:ident) => {
+        /// Arithmetic operators on amounts behave exactly like the ones of the underlying integer:
+        /// an overflow panics when overflow checks are enabled (debug builds by default) and wraps around
+        /// otherwise (release builds by default). Division and remainder by zero always panic.
+        /// Use the `checked_*` and `saturating_*` methods when the operands are not trusted.
+        impl<$($generics)*> Add for $ty {
+            type Output = $ty;
+
+            fn add(self, rhs: $ty) -> $ty {
+                <$ty>::$from(self.$as() + rhs.$as())
+            }
+        }
+
+        impl<$($generics)*> AddAssign for $ty {
+            fn add_assign(&mut self, rhs: $ty) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<$($generics)*> Sub for $ty {
+            type Output = $ty;
+
+            fn sub(self, rhs: $ty) -> $ty {
+                <$ty>::$from(self.$as() - rhs.$as())
+            }
+        }
+
+        impl<$($generics)*> SubAssign for $ty {
+            fn sub_assign(&mut self, rhs: $ty) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<$($generics)*> Mul<$int> for $ty {
+            type Output = $ty;
+
+            fn mul(self, rhs: $int) -> $ty {
+                <$ty>::$from(self.$as() * rhs)
+            }
+        }
+
+        impl<$($generics)*> Mul<$ty> for $int {
+            type Output = $ty;
+
+            fn mul(self, rhs: $ty) -> $ty {
+                rhs * self
+            }
+        }
+
+        impl<$($generics)*> MulAssign<$int> for $ty {
+            fn mul_assign(&mut self, rhs: $int) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<$($generics)*> Div<$int> for $ty {
+            type Output = $ty;
+
+            fn div(self, rhs: $int) -> $ty {
+                <$ty>::$from(self.$as() / rhs)
+            }
+        }
+
+        impl<$($generics)*> DivAssign<$int> for $ty {
+            fn div_assign(&mut self, rhs: $int) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<$($generics)*> Rem<$int> for $ty {
+            type Output = $ty;
+
+            fn rem(self, rhs: $int) -> $ty {
+                <$ty>::$from(self.$as() % rhs)
+            }
+        }
+
+        impl<$($generics)*> RemAssign<$int> for $ty {
+            fn rem_assign(&mut self, rhs: $int) {
+                *self = *self % rhs;
+            }
+        }
+
+        impl<$($generics)*> Sum for $ty {
+            fn sum<I: Iterator<Item = $ty>>(iter: I) -> $ty {
+                iter.fold(<$ty>::$from(0), Add::add)
+            }
+        }
+
+        impl<'a, $($generics)*> Sum<&'a $ty> for $ty {
+            fn sum<I: Iterator<Item = &'a $ty>>(iter: I) -> $ty {
+                iter.copied().sum()
+            }
+        }
+    };
+}
+
+impl_ops!([] UncToken, u128, from_attounc, as_attounc);
+impl_ops!([const DECIMALS: u8] TokenAmount<DECIMALS>, u128, from_base_units, as_base_units);
+impl_ops!([] UncGas, u64, from_gas, as_gas);
+impl_ops!([] StorageUsage, u64, from_bytes, as_bytes);
+
+/// The cost of gas at a gas price. Unlike the other operators it always panics if the cost
+/// does not fit into `UncToken`; use [`UncGas::checked_cost`] when the operands are not trusted.
+impl Mul<GasPrice> for UncGas {
+    type Output = UncToken;
+
+    fn mul(self, rhs: GasPrice) -> UncToken {
+        self.checked_cost(rhs).expect("gas cost overflows UncToken")
+    }
+}
+
+impl Mul<UncGas> for GasPrice {
+    type Output = UncToken;
+
+    fn mul(self, rhs: UncGas) -> UncToken {
+        rhs * self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasPrice, StorageUsage, TokenAmount, UncGas, UncToken};
+
+    #[test]
+    fn add_sub_tokens() {
+        let mut tokens = UncToken::from_unc(1) + UncToken::from_milliunc(500);
+        assert_eq!(tokens, UncToken::from_milliunc(1500));
+        tokens -= UncToken::from_milliunc(250);
+        assert_eq!(tokens, UncToken::from_milliunc(1250));
+        tokens += UncToken::from_milliunc(750);
+        assert_eq!(tokens, UncToken::from_unc(2));
+        assert_eq!(tokens - UncToken::from_unc(2), UncToken::from_attounc(0));
+    }
+
+    #[test]
+    fn mul_div_rem_tokens() {
+        let mut tokens = UncToken::from_attounc(10);
+        assert_eq!(tokens * 3, UncToken::from_attounc(30));
+        assert_eq!(3 * tokens, UncToken::from_attounc(30));
+        assert_eq!(tokens / 3, UncToken::from_attounc(3));
+        assert_eq!(tokens % 3, UncToken::from_attounc(1));
+        tokens *= 4;
+        assert_eq!(tokens, UncToken::from_attounc(40));
+        tokens /= 8;
+        assert_eq!(tokens, UncToken::from_attounc(5));
+        tokens %= 3;
+        assert_eq!(tokens, UncToken::from_attounc(2));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn add_overflow_panics_in_debug() {
+        let _ = UncToken::from_attounc(u128::MAX) + UncToken::from_attounc(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero_panics() {
+        let _ = UncToken::from_attounc(1) / 0;
+    }
+
+    #[test]
+    fn sum_tokens() {
+        let balances = vec![
+            UncToken::from_unc(1),
+            UncToken::from_milliunc(200),
+            UncToken::from_attounc(3),
+        ];
+        let expected = UncToken::from_attounc(10u128.pow(24) + 2 * 10u128.pow(23) + 3);
+        assert_eq!(balances.iter().sum::<UncToken>(), expected);
+        assert_eq!(balances.into_iter().sum::<UncToken>(), expected);
+        assert_eq!(
+            std::iter::empty::<UncToken>().sum::<UncToken>(),
+            UncToken::from_attounc(0)
+        );
+    }
+
+    #[test]
+    fn token_amount_ops() {
+        type Usdc = TokenAmount<6>;
+
+        let mut amount = Usdc::from_whole(1) + Usdc::from_base_units(500_000);
+        assert_eq!(amount, Usdc::from_base_units(1_500_000));
+        amount -= Usdc::from_base_units(500_000);
+        assert_eq!(amount, Usdc::ONE);
+        assert_eq!(3 * amount, Usdc::from_whole(3));
+        assert_eq!(amount / 4, Usdc::from_base_units(250_000));
+        assert_eq!(Usdc::from_base_units(10) % 3, Usdc::from_base_units(1));
+        assert_eq!(
+            [Usdc::ONE, Usdc::from_whole(2)].iter().sum::<Usdc>(),
+            Usdc::from_whole(3)
+        );
+    }
+
+    #[test]
+    fn gas_ops() {
+        let mut gas = UncGas::from_tgas(10) + UncGas::from_ggas(500);
+        assert_eq!(gas, UncGas::from_ggas(10_500));
+        gas -= UncGas::from_ggas(500);
+        gas *= 3;
+        assert_eq!(gas, UncGas::from_tgas(30));
+        assert_eq!(gas / 3, UncGas::from_tgas(10));
+        assert_eq!(2 * gas, UncGas::from_tgas(60));
+        assert_eq!(
+            [UncGas::from_tgas(1), UncGas::from_tgas(2)]
+                .iter()
+                .sum::<UncGas>(),
+            UncGas::from_tgas(3)
+        );
+
+        let price = GasPrice::from_attounc(100_000_000);
+        assert_eq!(gas * price, UncToken::from_milliunc(3));
+        assert_eq!(price * gas, UncToken::from_milliunc(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "gas cost overflows UncToken")]
+    fn gas_cost_overflow_panics() {
+        let _ = UncGas::from_gas(2) * GasPrice::from_attounc(u128::MAX);
+    }
+
+    #[test]
+    fn storage_ops() {
+        let mut usage = StorageUsage::from_bytes(100) + StorageUsage::from_bytes(28);
+        usage -= StorageUsage::from_bytes(8);
+        usage *= 2;
+        assert_eq!(usage, StorageUsage::from_bytes(240));
+        assert_eq!(usage / 3, StorageUsage::from_bytes(80));
+        assert_eq!(
+            [usage, usage].into_iter().sum::<StorageUsage>(),
+            StorageUsage::from_bytes(480)
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! The PostgreSQL `NUMERIC` binary format shared by the `sqlx`, `diesel` and `postgres-types` integrations.
+//!
+//! A `NUMERIC` is sent as four big-endian 16-bit header fields: the number of digits, the weight
+//! (the power of 10000 of the first digit), the sign and the display scale, followed by the digits
+//! in base 10000. Trailing zero digits are omitted, so `10^24` is the single digit `1` with the weight 6.
+
+use std::error::Error;
+
+use crate::{ConversionError, UncToken};
+
+const NBASE: u128 = 10_000;
+
+/// `u128::MAX` has 39 decimal digits, that is 10 digits in base 10000.
+const MAX_DIGITS: usize = 10;
+
+/// The length of the largest encoded amount: the header and `MAX_DIGITS` digits.
+pub(crate) const MAX_NUMERIC_LEN: usize = 8 + 2 * MAX_DIGITS;
+
+const SIGN_POSITIVE: u16 = 0x0000;
+const SIGN_NEGATIVE: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xC000;
+const SIGN_POSITIVE_INFINITY: u16 = 0xD000;
+const SIGN_NEGATIVE_INFINITY: u16 = 0xF000;
+
+/// Encodes the amount as a `NUMERIC` number of atto-unc with no fractional digits into `buf`,
+/// returning the used part of it.
+pub(crate) fn encode_numeric(amount: UncToken, buf: &mut [u8; MAX_NUMERIC_LEN]) -> &[u8] {
+    let mut digits = [0u16; MAX_DIGITS];
+    let mut attounc = amount.as_attounc();
+    let mut count = 0;
+    while attounc > 0 {
+        digits[count] = (attounc % NBASE) as u16;
+        attounc /= NBASE;
+        count += 1;
+    }
+    let weight = count.saturating_sub(1);
+    // `digits` holds the least significant digit first, so the trailing zeros are at its start.
+    let trailing_zeros = digits[..count]
+        .iter()
+        .take_while(|&&digit| digit == 0)
+        .count();
+    let significant = &digits[trailing_zeros..count];
+
+    let header = [significant.len() as u16, weight as u16, SIGN_POSITIVE, 0];
+    let mut len = 0;
+    for field in header.iter().chain(significant.iter().rev()) {
+        buf[len..len + 2].copy_from_slice(&field.to_be_bytes());
+        len += 2;
+    }
+    &buf[..len]
+}
+
+/// Decodes a binary `NUMERIC` number of atto-unc. Negative numbers, NaN, infinities, fractions of an atto-unc
+/// and numbers that do not fit into `UncToken` are rejected with the matching [`ConversionError`].
+pub(crate) fn decode_numeric(bytes: &[u8]) -> Result<UncToken, Box<dyn Error + Send + Sync>> {
+    let field = |index: usize| -> Result<u16, Box<dyn Error + Send + Sync>> {
+        match bytes.get(2 * index..2 * index + 2) {
+            Some(field) => Ok(u16::from_be_bytes([field[0], field[1]])),
+            None => Err("invalid NUMERIC value: unexpected end of input".into()),
+        }
+    };
+    let count = field(0)? as usize;
+    let weight = field(1)? as i16;
+    match field(2)? {
+        SIGN_POSITIVE => {}
+        SIGN_NEGATIVE => return Err(ConversionError::NegativeAmount.into()),
+        SIGN_NAN => return Err(ConversionError::NotANumber.into()),
+        SIGN_POSITIVE_INFINITY | SIGN_NEGATIVE_INFINITY => {
+            return Err(ConversionError::Infinite.into())
+        }
+        sign => return Err(format!("invalid NUMERIC value: unknown sign {:#06x}", sign).into()),
+    }
+    if bytes.len() != 8 + 2 * count {
+        return Err("invalid NUMERIC value: wrong number of digits".into());
+    }
+
+    let mut attounc: u128 = 0;
+    for index in 0..count {
+        let digit = field(4 + index)?;
+        if u128::from(digit) >= NBASE {
+            return Err(format!("invalid NUMERIC value: digit {} is out of range", digit).into());
+        }
+        // Digits after the decimal point have a negative weight.
+        if i32::from(weight) - (index as i32) < 0 {
+            if digit != 0 {
+                return Err(ConversionError::PrecisionLoss.into());
+            }
+            continue;
+        }
+        attounc = attounc
+            .checked_mul(NBASE)
+            .and_then(|attounc| attounc.checked_add(digit.into()))
+            .ok_or(ConversionError::OutOfRange)?;
+    }
+    // The omitted trailing zero digits of the whole part.
+    let mut omitted = i32::from(weight) + 1 - count as i32;
+    while omitted > 0 && attounc > 0 {
+        attounc = attounc
+            .checked_mul(NBASE)
+            .ok_or(ConversionError::OutOfRange)?;
+        omitted -= 1;
+    }
+    Ok(UncToken::from_attounc(attounc))
+}
+
+/// Decodes a `NUMERIC` number of atto-unc sent in the text format, e.g. `"1500000000000000000000000"`.
+#[cfg(feature = "sqlx-postgres")]
+pub(crate) fn decode_numeric_text(text: &str) -> Result<UncToken, Box<dyn Error + Send + Sync>> {
+    match text {
+        "NaN" => return Err(ConversionError::NotANumber.into()),
+        "Infinity" | "-Infinity" => return Err(ConversionError::Infinite.into()),
+        _ if text.starts_with('-') => return Err(ConversionError::NegativeAmount.into()),
+        _ => {}
+    }
+    // `NUMERIC` columns with a scale keep zeros after the decimal point, e.g. `"5.000"`.
+    let whole = match text.split_once('.') {
+        Some((whole, fraction)) if fraction.bytes().all(|digit| digit == b'0') => whole,
+        Some(_) => return Err(ConversionError::PrecisionLoss.into()),
+        None => text,
+    };
+    Ok(UncToken::from_attounc(whole.parse()?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode(attounc: u128) -> Vec<u8> {
+        encode_numeric(UncToken::from_attounc(attounc), &mut [0; MAX_NUMERIC_LEN]).to_vec()
+    }
+
+    fn numeric(count: u16, weight: i16, sign: u16, scale: u16, digits: &[u16]) -> Vec<u8> {
+        [count, weight as u16, sign, scale]
+            .iter()
+            .chain(digits)
+            .flat_map(|field| field.to_be_bytes())
+            .collect()
+    }
+
+    fn decode_err(bytes: &[u8]) -> String {
+        decode_numeric(bytes).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn encode_numeric_amounts() {
+        assert_eq!(encode(0), numeric(0, 0, 0, 0, &[]));
+        assert_eq!(encode(1), numeric(1, 0, 0, 0, &[1]));
+        assert_eq!(encode(12_345), numeric(2, 1, 0, 0, &[1, 2_345]));
+        assert_eq!(encode(10u128.pow(24)), numeric(1, 6, 0, 0, &[1]));
+        assert_eq!(encode(100_000_001), numeric(3, 2, 0, 0, &[1, 0, 1]));
+        assert_eq!(
+            encode(u128::MAX),
+            numeric(
+                10,
+                9,
+                0,
+                0,
+                &[340, 2823, 6692, 938, 4634, 6337, 4607, 4317, 6821, 1455]
+            )
+        );
+    }
+
+    #[test]
+    fn decode_numeric_amounts() {
+        for attounc in [0, 1, 9_999, 10_000, 10u128.pow(24), 100_000_001, u128::MAX] {
+            assert_eq!(
+                decode_numeric(&encode(attounc)).unwrap(),
+                UncToken::from_attounc(attounc)
+            );
+        }
+        // Zero fractional digits of a column with a scale, e.g. `NUMERIC(39, 4)`.
+        assert_eq!(
+            decode_numeric(&numeric(2, 0, 0, 4, &[5, 0])).unwrap(),
+            UncToken::from_attounc(5)
+        );
+        // Zero with a negative weight, as sent for `0.0000`.
+        assert_eq!(
+            decode_numeric(&numeric(0, -1, 0, 4, &[])).unwrap(),
+            UncToken::from_attounc(0)
+        );
+    }
+
+    #[test]
+    fn decode_invalid_numeric() {
+        assert_eq!(
+            decode_err(&numeric(1, 0, SIGN_NEGATIVE, 0, &[1])),
+            "negative tokens amount"
+        );
+        assert_eq!(
+            decode_err(&numeric(0, 0, SIGN_NAN, 0, &[])),
+            "tokens amount is not a number"
+        );
+        assert_eq!(
+            decode_err(&numeric(0, 0, SIGN_NEGATIVE_INFINITY, 0, &[])),
+            "infinite tokens amount"
+        );
+        assert_eq!(
+            decode_err(&numeric(2, 0, 0, 1, &[1, 5_000])),
+            "tokens amount cannot be represented without precision loss"
+        );
+        assert_eq!(
+            decode_err(&numeric(1, 10, 0, 0, &[1])),
+            "tokens amount is out of range"
+        );
+        assert_eq!(
+            decode_err(&numeric(
+                10,
+                9,
+                0,
+                0,
+                &[340, 2823, 6692, 938, 4634, 6337, 4607, 4317, 6821, 1456]
+            )),
+            "tokens amount is out of range"
+        );
+        assert_eq!(
+            decode_err(&numeric(1, 0, 0x1234, 0, &[1])),
+            "invalid NUMERIC value: unknown sign 0x1234"
+        );
+        assert_eq!(
+            decode_err(&numeric(2, 1, 0, 0, &[1])),
+            "invalid NUMERIC value: wrong number of digits"
+        );
+        assert_eq!(
+            decode_err(&numeric(1, 0, 0, 0, &[10_000])),
+            "invalid NUMERIC value: digit 10000 is out of range"
+        );
+        assert_eq!(
+            decode_err(&[0, 1]),
+            "invalid NUMERIC value: unexpected end of input"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-postgres")]
+    fn decode_numeric_texts() {
+        assert_eq!(
+            decode_numeric_text("340282366920938463463374607431768211455").unwrap(),
+            UncToken::from_attounc(u128::MAX)
+        );
+        assert_eq!(
+            decode_numeric_text("5.000").unwrap(),
+            UncToken::from_attounc(5)
+        );
+        assert_eq!(
+            decode_numeric_text("5.5").unwrap_err().to_string(),
+            "tokens amount cannot be represented without precision loss"
+        );
+        assert_eq!(
+            decode_numeric_text("-5").unwrap_err().to_string(),
+            "negative tokens amount"
+        );
+        assert_eq!(
+            decode_numeric_text("NaN").unwrap_err().to_string(),
+            "tokens amount is not a number"
+        );
+        assert!(decode_numeric_text("340282366920938463463374607431768211456").is_err());
+    }
+}
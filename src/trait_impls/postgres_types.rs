@@ -0,0 +1,73 @@
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::trait_impls::pg_numeric::{decode_numeric, encode_numeric, MAX_NUMERIC_LEN};
+use crate::UncToken;
+
+/// An amount is stored as a `NUMERIC` number of atto-unc, e.g. in a `NUMERIC(39, 0)` column
+/// (`u128::MAX` has 39 digits).
+impl ToSql for UncToken {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(encode_numeric(*self, &mut [0; MAX_NUMERIC_LEN]));
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::NUMERIC
+    }
+
+    to_sql_checked!();
+}
+
+/// Negative numbers, NaN, infinities, fractions of an atto-unc and numbers that do not fit into `UncToken`
+/// are rejected with the matching [`ConversionError`](crate::ConversionError).
+impl<'a> FromSql<'a> for UncToken {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        decode_numeric(raw)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::NUMERIC
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    use crate::{ConversionError, UncToken};
+
+    #[test]
+    fn numeric_round_trip() {
+        for attounc in [0, 1, 10u128.pow(24), u128::MAX] {
+            let amount = UncToken::from_attounc(attounc);
+            let mut buf = BytesMut::new();
+            amount.to_sql_checked(&Type::NUMERIC, &mut buf).unwrap();
+            assert_eq!(UncToken::from_sql(&Type::NUMERIC, &buf).unwrap(), amount);
+        }
+    }
+
+    #[test]
+    fn numeric_type_is_required() {
+        let mut buf = BytesMut::new();
+        assert!(UncToken::from_attounc(1)
+            .to_sql_checked(&Type::TEXT, &mut buf)
+            .is_err());
+        assert!(<UncToken as FromSql>::accepts(&Type::NUMERIC));
+        assert!(!<UncToken as FromSql>::accepts(&Type::INT8));
+    }
+
+    #[test]
+    fn negative_numeric_is_rejected() {
+        // -1 in the binary `NUMERIC` format.
+        let raw = [0, 1, 0, 0, 0x40, 0, 0, 0, 0, 1];
+        let err = UncToken::from_sql(&Type::NUMERIC, &raw).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ConversionError>(),
+            Some(&ConversionError::NegativeAmount)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::string::ToString;
+
+use rust_decimal::Decimal;
+
+use crate::trait_impls::display::UNC_DECIMALS;
+use crate::utils::parse_decimal;
+use crate::{ConversionError, UncToken, UncTokenError};
+
+/// A `Decimal` is read as a number of UNC, the same way as the number part of `"1.5 UNC"` is parsed:
+/// negative numbers and fractions of an atto-unc are rejected with `UncTokenError::InvalidTokensAmount`.
+/// The spans of the error are byte ranges of the normalized number, `value.normalize().to_string()`.
+///
+/// # Examples
+/// ```
+/// use rust_decimal::Decimal;
+/// use unc_token::UncToken;
+///
+/// let amount = UncToken::try_from(Decimal::new(15, 1)).unwrap();
+/// assert_eq!(amount, UncToken::from_milliunc(1_500));
+/// assert!(UncToken::try_from(Decimal::new(-15, 1)).is_err());
+/// assert!(UncToken::try_from(Decimal::new(1, 25)).is_err());
+/// ```
+impl TryFrom<Decimal> for UncToken {
+    type Error = UncTokenError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        // Trailing zeros beyond 24 fractional digits do not make the amount more precise than an atto-unc.
+        let number = value.normalize().to_string();
+        parse_decimal(&number, UNC_DECIMALS as u32)
+            .map(UncToken::from_attounc)
+            .map_err(UncTokenError::InvalidTokensAmount)
+    }
+}
+
+/// The amount is converted to a number of UNC with a scale of 24, so every atto-unc is kept.
+///
+/// `Decimal` has a 96-bit mantissa, so amounts above 79228 UNC have their trailing zeros dropped
+/// from the scale, and fail with `ConversionError::PrecisionLoss` if that is not enough.
+///
+/// # Examples
+/// ```
+/// use rust_decimal::Decimal;
+/// use unc_token::{ConversionError, UncToken};
+///
+/// let decimal = Decimal::try_from(UncToken::from_milliunc(1_500)).unwrap();
+/// assert_eq!(decimal, Decimal::new(15, 1));
+/// assert_eq!(decimal.scale(), 24);
+/// assert_eq!(Decimal::try_from(UncToken::from_unc(1_000_000)), Ok(Decimal::new(1_000_000, 0)));
+/// assert_eq!(
+///     Decimal::try_from(UncToken::from_attounc(u128::MAX)),
+///     Err(ConversionError::PrecisionLoss)
+/// );
+/// ```
+impl TryFrom<UncToken> for Decimal {
+    type Error = ConversionError;
+
+    fn try_from(value: UncToken) -> Result<Self, Self::Error> {
+        let max_mantissa = Decimal::MAX.mantissa() as u128;
+        let mut mantissa = value.as_attounc();
+        let mut scale = UNC_DECIMALS as u32;
+        while mantissa > max_mantissa && scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        if mantissa > max_mantissa {
+            return Err(ConversionError::PrecisionLoss);
+        }
+        Decimal::try_from_i128_with_scale(mantissa as i128, scale)
+            .map_err(|_| ConversionError::PrecisionLoss)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal::Decimal;
+
+    use crate::{ConversionError, DecimalNumberParsingError, UncToken, UncTokenError};
+
+    #[test]
+    fn decimal_to_unc_token() {
+        assert_eq!(
+            UncToken::try_from(Decimal::new(1, 24)),
+            Ok(UncToken::from_attounc(1))
+        );
+        assert_eq!(
+            UncToken::try_from(Decimal::new(10_000, 28)),
+            Ok(UncToken::from_attounc(1))
+        );
+        assert_eq!(
+            UncToken::try_from(-Decimal::ZERO),
+            Ok(UncToken::from_attounc(0))
+        );
+        assert_eq!(
+            UncToken::try_from(Decimal::MAX),
+            Err(UncTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::LongWhole(crate::InputFragment::new(0..29, 0..29))
+            ))
+        );
+
+        let err = UncToken::try_from(Decimal::new(-25, 1)).unwrap_err();
+        assert_eq!(
+            err.display("-2.5").to_string(),
+            "invalid tokens amount: invalid number: -2.5"
+        );
+        assert_eq!(err.span(), 0..1);
+
+        let err = UncToken::try_from(Decimal::new(12, 25)).unwrap_err();
+        assert_eq!(
+            err,
+            UncTokenError::InvalidTokensAmount(DecimalNumberParsingError::LongFractional(
+                crate::InputFragment::new(2..27, 2..27)
+            ))
+        );
+    }
+
+    #[test]
+    fn unc_token_to_decimal() {
+        assert_eq!(
+            Decimal::try_from(UncToken::from_attounc(1)),
+            Ok(Decimal::new(1, 24))
+        );
+        let largest_exact = UncToken::from_attounc(Decimal::MAX.mantissa() as u128);
+        assert_eq!(
+            Decimal::try_from(largest_exact).map(|decimal| decimal.scale()),
+            Ok(24)
+        );
+        assert_eq!(
+            Decimal::try_from(largest_exact.saturating_add(UncToken::from_attounc(1))),
+            Err(ConversionError::PrecisionLoss)
+        );
+        assert_eq!(
+            Decimal::try_from(UncToken::from_unc(300_000_000_000_000)),
+            Ok(Decimal::new(300_000_000_000_000, 0))
+        );
+        for attounc in [0, 1, 10u128.pow(24), 79_228 * 10u128.pow(24) + 1] {
+            let amount = UncToken::from_attounc(attounc);
+            assert_eq!(
+                UncToken::try_from(Decimal::try_from(amount).unwrap()),
+                Ok(amount)
+            );
+        }
+    }
+}
@@ -1,15 +1,439 @@
-use crate::UncToken;
+use std::borrow::Cow;
 
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{
+    InstanceType, Metadata, NumberValidation, Schema, SchemaObject, StringValidation,
+};
+
+use crate::{GasPrice, StoragePrice, StorageUsage, TokenAmount, UncGas, UncToken};
+
+/// UncToken is described as a named definition: a string of digits with an amount of atto-UNC, matching its
+/// `Serialize` implementation. Like its `Deserialize` implementation, the schema also admits a leading `+`
+/// and integer numbers.
 impl schemars::JsonSchema for UncToken {
-    fn is_referenceable() -> bool {
-        false
+    fn schema_name() -> String {
+        "UncToken".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("unc_token::UncToken")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        base_units_schema(
+            "Amount of UNC tokens in atto-UNC (1 UNC = 10^24 atto-UNC) as a string of decimal digits.",
+            &["1000000000000000000000000", "1500000000000000000000", "0"],
+        )
+    }
+}
+
+/// TokenAmount is described like `UncToken`, with a definition per number of decimals, e.g. `TokenAmount6`.
+impl<const DECIMALS: u8> schemars::JsonSchema for TokenAmount<DECIMALS> {
+    fn schema_name() -> String {
+        format!("TokenAmount{}", DECIMALS)
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Owned(format!("unc_token::TokenAmount<{}>", DECIMALS))
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        base_units_schema(
+            &format!(
+                "Amount of fungible tokens in base units (1 token = 10^{} base units) as a string of decimal digits.",
+                DECIMALS
+            ),
+            &[&Self::ONE.as_base_units().to_string(), "0"],
+        )
+    }
+}
+
+/// UncGas is described as a non-negative 64-bit integer number of gas units.
+impl schemars::JsonSchema for UncGas {
+    fn schema_name() -> String {
+        "UncGas".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("unc_token::UncGas")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "Amount of gas in gas units (1 Tgas = 10^12 gas units) as an integer number."
+                        .to_owned(),
+                ),
+                examples: vec![30_000_000_000_000u64.into()],
+                ..Default::default()
+            })),
+            instance_type: Some(InstanceType::Integer.into()),
+            format: Some("uint64".to_owned()),
+            number: Some(Box::new(NumberValidation {
+                minimum: Some(0.0),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// GasPrice is described like `UncToken`, as a string with an amount of atto-UNC per unit of gas.
+impl schemars::JsonSchema for GasPrice {
+    fn schema_name() -> String {
+        "GasPrice".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("unc_token::GasPrice")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        base_units_schema(
+            "Price of one unit of gas in atto-UNC as a string of decimal digits.",
+            &["100000000"],
+        )
+    }
+}
+
+/// StorageUsage is described as a non-negative 64-bit integer number of bytes.
+impl schemars::JsonSchema for StorageUsage {
+    fn schema_name() -> String {
+        "StorageUsage".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("unc_token::StorageUsage")
     }
 
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some("Amount of account storage in bytes.".to_owned()),
+                examples: vec![2000.into()],
+                ..Default::default()
+            })),
+            instance_type: Some(InstanceType::Integer.into()),
+            format: Some("uint64".to_owned()),
+            number: Some(Box::new(NumberValidation {
+                minimum: Some(0.0),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// StoragePrice is described like `UncToken`, as a string with an amount of atto-UNC per byte.
+impl schemars::JsonSchema for StoragePrice {
     fn schema_name() -> String {
-        String::schema_name()
+        "StoragePrice".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("unc_token::StoragePrice")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        base_units_schema(
+            "Amount of atto-UNC locked per byte of storage as a string of decimal digits.",
+            &["10000000000000000000"],
+        )
     }
+}
+
+/// Returns the schema of an amount of base units that is serialized as a string of digits and deserialized
+/// from such a string with an optional leading `+`, or from an integer. The length of the string is not limited,
+/// as leading zeros are accepted; the amount must fit into `u128`.
+fn base_units_schema(description: &str, examples: &[&str]) -> Schema {
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_owned()),
+            examples: examples.iter().map(|example| (*example).into()).collect(),
+            ..Default::default()
+        })),
+        instance_type: Some(vec![InstanceType::String, InstanceType::Integer].into()),
+        string: Some(Box::new(StringValidation {
+            max_length: None,
+            min_length: Some(1),
+            pattern: Some("^\\+?[0-9]+$".to_owned()),
+        })),
+        number: Some(Box::new(NumberValidation {
+            minimum: Some(0.0),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Returns a string schema with the given description, pattern and examples.
+#[cfg(feature = "serde")]
+fn string_schema(description: &str, pattern: &str, examples: &[&str]) -> Schema {
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_owned()),
+            examples: examples.iter().map(|example| (*example).into()).collect(),
+            ..Default::default()
+        })),
+        instance_type: Some(InstanceType::String.into()),
+        string: Some(Box::new(StringValidation {
+            max_length: None,
+            min_length: Some(1),
+            pattern: Some(pattern.to_owned()),
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+#[cfg(feature = "serde")]
+mod serde_modes {
+    use std::borrow::Cow;
+
+    use schemars::gen::SchemaGenerator;
+    use schemars::schema::{
+        InstanceType, Metadata, NumberValidation, Schema, SchemaObject, SubschemaValidation,
+    };
+    use schemars::JsonSchema;
+
+    use super::string_schema;
+    use crate::serde::{as_attounc, as_number, as_unc_decimal, as_unc_human, lenient};
+    use crate::UncToken;
+
+    impl JsonSchema for as_attounc::Schema {
+        fn is_referenceable() -> bool {
+            false
+        }
+
+        fn schema_name() -> String {
+            UncToken::schema_name()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            UncToken::schema_id()
+        }
+
+        fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+            gen.subschema_for::<UncToken>()
+        }
+    }
+
+    impl JsonSchema for as_unc_human::Schema {
+        fn schema_name() -> String {
+            "UncTokenHuman".to_owned()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            Cow::Borrowed("unc_token::serde::as_unc_human")
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            string_schema(
+                "Amount of UNC tokens as a decimal number with a unit, e.g. `1.5 UNC`. \
+                 Accepted units: kUNC, UNC or N, mUNC (milli), \u{b5}UNC or uUNC (micro), nUNC, pUNC, fUNC, aUNC or aN (atto), \
+                 and their full names such as `milliUNC`. Units are case-insensitive, except that `MUNC` and `PUNC` \
+                 are rejected: `M` and `P` are the SI prefixes mega and peta.",
+                "^\\s*\\+?[0-9]+(\\.[0-9]+)?\\s*[A-Za-z\u{b5}\u{3bc}]+\\s*$",
+                &["1.5 UNC", "500 milliUNC", "1 aUNC"],
+            )
+        }
+    }
+
+    impl JsonSchema for as_unc_decimal::Schema {
+        fn schema_name() -> String {
+            "UncTokenDecimal".to_owned()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            Cow::Borrowed("unc_token::serde::as_unc_decimal")
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            string_schema(
+                "Amount of UNC tokens as a decimal number of UNC with up to 24 digits after the floating point \
+                 (1 atto-UNC = 10^-24 UNC).",
+                "^\\+?[0-9]+(\\.[0-9]{1,24})?$",
+                &["1.5", "0.000000000000000000000001", "0"],
+            )
+        }
+    }
+
+    impl JsonSchema for as_number::Schema {
+        fn schema_name() -> String {
+            "UncTokenNumber".to_owned()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            Cow::Borrowed("unc_token::serde::as_number")
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            SchemaObject {
+                metadata: Some(Box::new(Metadata {
+                    description: Some(
+                        "Amount of UNC tokens in atto-UNC (1 UNC = 10^24 atto-UNC) as an integer number \
+                         of at most 2^128 - 1, which has no exact floating point representation for `maximum`."
+                            .to_owned(),
+                    ),
+                    examples: vec![1000.into(), 0.into()],
+                    ..Default::default()
+                })),
+                instance_type: Some(InstanceType::Integer.into()),
+                number: Some(Box::new(NumberValidation {
+                    minimum: Some(0.0),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+            .into()
+        }
+    }
+
+    impl JsonSchema for lenient::Schema {
+        fn schema_name() -> String {
+            "UncTokenLenient".to_owned()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            Cow::Borrowed("unc_token::serde::lenient")
+        }
+
+        fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+            SchemaObject {
+                metadata: Some(Box::new(Metadata {
+                    description: Some(
+                        "Amount of UNC tokens in atto-UNC, as a string or an integer, as a decimal number of UNC \
+                         with a decimal point, or as a decimal number with a unit."
+                            .to_owned(),
+                    ),
+                    ..Default::default()
+                })),
+                subschemas: Some(Box::new(SubschemaValidation {
+                    any_of: Some(vec![
+                        gen.subschema_for::<UncToken>(),
+                        gen.subschema_for::<as_unc_decimal::Schema>(),
+                        gen.subschema_for::<as_unc_human::Schema>(),
+                        gen.subschema_for::<as_number::Schema>(),
+                    ]),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+            .into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::UncToken;
+
+    #[test]
+    fn schema() {
+        let schema = schemars::schema_for!(UncToken);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            serde_json::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "UncToken",
+                "description": "Amount of UNC tokens in atto-UNC (1 UNC = 10^24 atto-UNC) as a string of decimal digits.",
+                "examples": ["1000000000000000000000000", "1500000000000000000000", "0"],
+                "type": ["string", "integer"],
+                "minLength": 1,
+                "pattern": "^\\+?[0-9]+$",
+                "minimum": 0.0
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn schema_of_serde_modes() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Transfer {
+            amount: UncToken,
+            #[schemars(with = "crate::serde::as_attounc::Schema")]
+            attounc: UncToken,
+            #[schemars(with = "crate::serde::as_unc_human::Schema")]
+            human: UncToken,
+            #[schemars(with = "crate::serde::as_unc_decimal::Schema")]
+            decimal: UncToken,
+            #[schemars(with = "crate::serde::as_number::Schema")]
+            number: UncToken,
+            #[schemars(with = "crate::serde::lenient::Schema")]
+            lenient: UncToken,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Transfer)).unwrap();
+        let properties = &schema["properties"];
+        assert_eq!(properties["amount"]["$ref"], "#/definitions/UncToken");
+        assert_eq!(properties["attounc"]["$ref"], "#/definitions/UncToken");
+        assert_eq!(properties["human"]["$ref"], "#/definitions/UncTokenHuman");
+        assert_eq!(
+            properties["decimal"]["$ref"],
+            "#/definitions/UncTokenDecimal"
+        );
+        assert_eq!(properties["number"]["$ref"], "#/definitions/UncTokenNumber");
+        assert_eq!(
+            properties["lenient"]["$ref"],
+            "#/definitions/UncTokenLenient"
+        );
+
+        let definitions = &schema["definitions"];
+        assert_eq!(
+            definitions["UncTokenDecimal"]["pattern"],
+            "^\\+?[0-9]+(\\.[0-9]{1,24})?$"
+        );
+        assert_eq!(definitions["UncTokenNumber"]["type"], "integer");
+        assert!(definitions["UncTokenNumber"]["maximum"].is_null());
+        assert_eq!(
+            definitions["UncTokenLenient"]["anyOf"],
+            serde_json::json!([
+                { "$ref": "#/definitions/UncToken" },
+                { "$ref": "#/definitions/UncTokenDecimal" },
+                { "$ref": "#/definitions/UncTokenHuman" },
+                { "$ref": "#/definitions/UncTokenNumber" }
+            ])
+        );
+    }
+
+    #[test]
+    fn schema_of_token_amount() {
+        let schema = schemars::schema_for!(crate::TokenAmount<6>);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            serde_json::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "TokenAmount6",
+                "description": "Amount of fungible tokens in base units (1 token = 10^6 base units) as a string of decimal digits.",
+                "examples": ["1000000", "0"],
+                "type": ["string", "integer"],
+                "minLength": 1,
+                "pattern": "^\\+?[0-9]+$",
+                "minimum": 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn schema_of_gas_and_storage() {
+        let schema = serde_json::to_value(schemars::schema_for!(crate::UncGas)).unwrap();
+        assert_eq!(schema["type"], "integer");
+        assert_eq!(schema["format"], "uint64");
+        assert_eq!(schema["minimum"], 0.0);
+
+        let schema = serde_json::to_value(schemars::schema_for!(crate::GasPrice)).unwrap();
+        assert_eq!(schema["type"], serde_json::json!(["string", "integer"]));
+        assert_eq!(schema["pattern"], "^\\+?[0-9]+$");
 
-    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
-        String::json_schema(gen)
+        let schema = serde_json::to_value(schemars::schema_for!(crate::StorageUsage)).unwrap();
+        assert_eq!(schema["type"], "integer");
+        let schema = serde_json::to_value(schemars::schema_for!(crate::StoragePrice)).unwrap();
+        assert_eq!(schema["type"], serde_json::json!(["string", "integer"]));
     }
 }
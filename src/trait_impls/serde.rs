@@ -1,43 +1,372 @@
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::UncToken;
+use crate::{GasPrice, StoragePrice, StorageUsage, TokenAmount, UncGas, UncToken};
 
 impl Serialize for UncToken {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        use serde::ser::Error;
-        let mut buf = [0u8; 40];
-        let remainder = {
-            use std::io::Write;
-            let mut w: &mut [u8] = &mut buf;
-            write!(w, "{}", self.inner)
-                .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
-            w.len()
-        };
-        let len = buf.len() - remainder;
+        serialize_base_units(self.inner, serializer)
+    }
+}
 
-        let s = std::str::from_utf8(&buf[..len])
-            .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
-        serializer.serialize_str(s)
+/// TokenAmount is serialized like `UncToken`: as a string with an amount of base units, e.g. `"2500000"`.
+impl<const DECIMALS: u8> Serialize for TokenAmount<DECIMALS> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_base_units(self.as_base_units(), serializer)
     }
 }
 
+/// GasPrice is serialized like `UncToken`: as a string with an amount of atto-unc per unit of gas.
+impl Serialize for GasPrice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_base_units(self.as_attounc(), serializer)
+    }
+}
+
+/// UncGas is serialized as an integer number of gas units, e.g. `30000000000000`.
+impl Serialize for UncGas {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.as_gas())
+    }
+}
+
+/// StorageUsage is serialized as an integer number of bytes.
+impl Serialize for StorageUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.as_bytes())
+    }
+}
+
+/// StoragePrice is serialized like `UncToken`: as a string with an amount of atto-unc per byte.
+impl Serialize for StoragePrice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_base_units(self.as_attounc_per_byte(), serializer)
+    }
+}
+
+/// Serializes `value` as a string of decimal digits without allocating.
+fn serialize_base_units<S: Serializer>(mut value: u128, serializer: S) -> Result<S::Ok, S::Error> {
+    // `u128::MAX` has 39 decimal digits.
+    let mut buf = [0u8; 39];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    let s = core::str::from_utf8(&buf[start..]).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(s)
+}
+
+/// UncToken is deserialized from a string with an amount of atto-unc, e.g. `"1000"` or `"+1000"`.
+///
+/// Human-readable self-describing formats (e.g. JSON) also accept integer numbers of atto-unc, e.g. `1000`,
+/// and floating point numbers that hold an integer not larger than 2^53, so no precision could have been lost,
+/// e.g. `1000.0` or `1e3`. Borrowed strings are parsed without allocation.
+///
+/// Integers above `u64::MAX` are accepted from formats that pass them as `u128`. `serde_json` passes them
+/// as floating point numbers instead (unless its `arbitrary_precision` feature is enabled), so such JSON numbers
+/// are rejected and larger amounts have to be written as strings, e.g. `"18446744073709551616"`.
 impl<'de> Deserialize<'de> for UncToken {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s: String = Deserialize::deserialize(deserializer)?;
-        s.parse::<u128>()
-            .map(UncToken::from_attounc)
-            .map_err(|err| de::Error::custom(err.to_string()))
+        deserialize_base_units(deserializer, ATTOUNC_VISITOR)
+    }
+}
+
+/// TokenAmount is deserialized like `UncToken`, from a string or a number with an amount of base units.
+impl<'de, const DECIMALS: u8> Deserialize<'de> for TokenAmount<DECIMALS> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_base_units(
+            deserializer,
+            BaseUnitsVisitor {
+                base_unit: "base units",
+                from_base_units: TokenAmount::from_base_units,
+            },
+        )
+    }
+}
+
+/// GasPrice is deserialized like `UncToken`, from a string or a number with an amount of atto-unc per unit of gas.
+impl<'de> Deserialize<'de> for GasPrice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_base_units(
+            deserializer,
+            BaseUnitsVisitor {
+                base_unit: "atto-unc per gas",
+                from_base_units: GasPrice::from_attounc,
+            },
+        )
+    }
+}
+
+/// UncGas is deserialized from an integer number of gas units. Human-readable self-describing formats
+/// (e.g. JSON) also accept a string with the number, e.g. `"30000000000000"`.
+impl<'de> Deserialize<'de> for UncGas {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_u64_amount(
+            deserializer,
+            U64AmountVisitor {
+                unit: "gas units",
+                from_u64: UncGas::from_gas,
+            },
+        )
+    }
+}
+
+/// StorageUsage is deserialized like `UncGas`, from an integer number of bytes.
+impl<'de> Deserialize<'de> for StorageUsage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_u64_amount(
+            deserializer,
+            U64AmountVisitor {
+                unit: "bytes",
+                from_u64: StorageUsage::from_bytes,
+            },
+        )
+    }
+}
+
+/// Deserializes an integer amount, accepting strings only from human-readable formats.
+fn deserialize_u64_amount<'de, D, T>(
+    deserializer: D,
+    visitor: U64AmountVisitor<T>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_u64(visitor)
+    }
+}
+
+/// Visits an integer or a string with a `u64` number of `unit`s.
+struct U64AmountVisitor<T> {
+    unit: &'static str,
+    from_u64: fn(u64) -> T,
+}
+
+impl<'de, T> de::Visitor<'de> for U64AmountVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            formatter,
+            "an integer or a string with a number of {}",
+            self.unit
+        )
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if !is_unsigned_integer(s) {
+            return Err(E::invalid_value(de::Unexpected::Str(s), &self));
+        }
+        match s.parse::<u64>() {
+            Ok(v) => Ok((self.from_u64)(v)),
+            Err(_) => Err(E::custom(format_args!(
+                "number of {} does not fit into u64: {}",
+                self.unit, s
+            ))),
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok((self.from_u64)(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => Ok((self.from_u64)(v)),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(v), &self)),
+        }
+    }
+}
+
+/// StoragePrice is deserialized like `UncToken`, from a string or a number with an amount of atto-unc per byte.
+impl<'de> Deserialize<'de> for StoragePrice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_base_units(
+            deserializer,
+            BaseUnitsVisitor {
+                base_unit: "atto-unc per byte",
+                from_base_units: StoragePrice::from_attounc_per_byte,
+            },
+        )
+    }
+}
+
+/// Deserializes an amount of base units, accepting numbers only from human-readable formats.
+fn deserialize_base_units<'de, D, T>(
+    deserializer: D,
+    visitor: BaseUnitsVisitor<T>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_str(visitor)
+    }
+}
+
+/// Returns whether `s` is a non-negative integer in the format of `u128::from_str`: decimal digits
+/// with an optional leading `+`.
+pub(crate) fn is_unsigned_integer(s: &str) -> bool {
+    let digits = s.strip_prefix('+').unwrap_or(s);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// The largest integer up to which every integer can be represented by `f64` exactly.
+const MAX_EXACT_F64_INTEGER: f64 = (1u64 << f64::MANTISSA_DIGITS) as f64;
+
+/// Visits a string or a number with an integer amount of `base_unit`s, see the `Deserialize` implementation of `UncToken`.
+pub(crate) struct BaseUnitsVisitor<T> {
+    pub(crate) base_unit: &'static str,
+    pub(crate) from_base_units: fn(u128) -> T,
+}
+
+/// Visits an amount of atto-unc.
+pub(crate) const ATTOUNC_VISITOR: BaseUnitsVisitor<UncToken> = BaseUnitsVisitor {
+    base_unit: "atto-unc",
+    from_base_units: UncToken::from_attounc,
+};
+
+impl<'de, T> de::Visitor<'de> for BaseUnitsVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            formatter,
+            "a string or an integer with an amount of {}",
+            self.base_unit
+        )
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if !is_unsigned_integer(s) {
+            return Err(E::invalid_value(de::Unexpected::Str(s), &self));
+        }
+        match s.parse::<u128>() {
+            Ok(v) => Ok((self.from_base_units)(v)),
+            Err(_) => Err(E::custom(format_args!(
+                "{} amount does not fit into u128: {}",
+                self.base_unit, s
+            ))),
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok((self.from_base_units)(v.into()))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok((self.from_base_units)(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match u128::try_from(v) {
+            Ok(v) => Ok((self.from_base_units)(v)),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match u128::try_from(v) {
+            Ok(v) => Ok((self.from_base_units)(v)),
+            Err(_) => Err(E::custom(format_args!(
+                "invalid value: integer `{}`, expected a non-negative amount of {}",
+                v, self.base_unit
+            ))),
+        }
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if (0.0..=MAX_EXACT_F64_INTEGER).contains(&v) && v as u64 as f64 == v {
+            Ok((self.from_base_units)(v as u64 as u128))
+        } else if v > MAX_EXACT_F64_INTEGER && v.is_finite() {
+            Err(E::custom(format_args!(
+                "floating point number `{}` is too large to hold an exact amount of {}, use a string instead",
+                v, self.base_unit
+            )))
+        } else {
+            Err(E::invalid_value(de::Unexpected::Float(v), &self))
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use serde::de::value::U128Deserializer;
+    use serde::de::IntoDeserializer;
+    use serde::Deserialize;
+
     use crate::UncToken;
 
     #[test]
@@ -54,4 +383,180 @@ mod test {
         test_json_ser(8);
         test_json_ser(0);
     }
+
+    #[test]
+    fn json_de_numbers() {
+        for (json, expected) in [
+            ("1000", 1000),
+            ("0", 0),
+            ("18446744073709551615", u64::MAX.into()),
+            ("1000.0", 1000),
+            ("1e3", 1000),
+            ("9007199254740992.0", 1 << 53),
+            (r#""+5""#, 5),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<UncToken>(json).unwrap(),
+                UncToken::from_attounc(expected),
+                "input: {}",
+                json
+            );
+        }
+        let deserializer: U128Deserializer<serde::de::value::Error> = u128::MAX.into_deserializer();
+        assert_eq!(
+            UncToken::deserialize(deserializer),
+            Ok(UncToken::from_attounc(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn json_de_errors() {
+        for (json, expected) in [
+            (
+                r#""1.5 UNC""#,
+                "invalid value: string \"1.5 UNC\", expected a string or an integer with an amount of atto-unc at line 1 column 9",
+            ),
+            (
+                r#""""#,
+                "invalid value: string \"\", expected a string or an integer with an amount of atto-unc at line 1 column 2",
+            ),
+            (
+                r#""340282366920938463463374607431768211456""#,
+                "atto-unc amount does not fit into u128: 340282366920938463463374607431768211456 at line 1 column 41",
+            ),
+            (
+                "-1",
+                "invalid value: integer `-1`, expected a string or an integer with an amount of atto-unc at line 1 column 2",
+            ),
+            (
+                "1.5",
+                "invalid value: floating point `1.5`, expected a string or an integer with an amount of atto-unc at line 1 column 3",
+            ),
+            (
+                "1e30",
+                "floating point number `1000000000000000000000000000000` is too large to hold an exact amount of atto-unc, use a string instead at line 1 column 4",
+            ),
+            (
+                "true",
+                "invalid type: boolean `true`, expected a string or an integer with an amount of atto-unc at line 1 column 4",
+            ),
+            (
+                r#""+""#,
+                "invalid value: string \"+\", expected a string or an integer with an amount of atto-unc at line 1 column 3",
+            ),
+            (
+                r#""-5""#,
+                "invalid value: string \"-5\", expected a string or an integer with an amount of atto-unc at line 1 column 4",
+            ),
+            // `serde_json` passes integers above `u64::MAX` as `f64`, so they cannot be read exactly.
+            (
+                "18446744073709551616",
+                "floating point number `18446744073709552000` is too large to hold an exact amount of atto-unc, use a string instead at line 1 column 20",
+            ),
+            (
+                "340282366920938463463374607431768211455",
+                "floating point number `340282366920938500000000000000000000000` is too large to hold an exact amount of atto-unc, use a string instead at line 1 column 39",
+            ),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<UncToken>(json).unwrap_err().to_string(),
+                expected,
+                "input: {}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn json_token_amount() {
+        use crate::TokenAmount;
+
+        type Usdc = TokenAmount<6>;
+        let amount = Usdc::from_base_units(2_500_000);
+        assert_eq!(serde_json::to_string(&amount).unwrap(), r#""2500000""#);
+        assert_eq!(
+            serde_json::from_str::<Usdc>(r#""2500000""#).unwrap(),
+            amount
+        );
+        assert_eq!(serde_json::from_str::<Usdc>("2500000").unwrap(), amount);
+        assert_eq!(
+            serde_json::from_str::<Usdc>(r#""2.5""#)
+                .unwrap_err()
+                .to_string(),
+            "invalid value: string \"2.5\", expected a string or an integer with an amount of base units at line 1 column 5"
+        );
+    }
+
+    #[test]
+    fn json_gas() {
+        use crate::{GasPrice, UncGas};
+
+        let gas = UncGas::from_tgas(30);
+        assert_eq!(serde_json::to_string(&gas).unwrap(), "30000000000000");
+        assert_eq!(
+            serde_json::from_str::<UncGas>("30000000000000").unwrap(),
+            gas
+        );
+        assert_eq!(
+            serde_json::from_str::<UncGas>(r#""30000000000000""#).unwrap(),
+            gas
+        );
+        for (json, expected) in [
+            (
+                "-1",
+                "invalid value: integer `-1`, expected an integer or a string with a number of gas units at line 1 column 2",
+            ),
+            (
+                r#""30 Tgas""#,
+                "invalid value: string \"30 Tgas\", expected an integer or a string with a number of gas units at line 1 column 9",
+            ),
+            (
+                r#""18446744073709551616""#,
+                "number of gas units does not fit into u64: 18446744073709551616 at line 1 column 22",
+            ),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<UncGas>(json).unwrap_err().to_string(),
+                expected,
+                "input: {}",
+                json
+            );
+        }
+
+        let price = GasPrice::from_attounc(100_000_000);
+        assert_eq!(serde_json::to_string(&price).unwrap(), r#""100000000""#);
+        assert_eq!(
+            serde_json::from_str::<GasPrice>(r#""100000000""#).unwrap(),
+            price
+        );
+    }
+
+    #[test]
+    fn json_storage() {
+        use crate::{StoragePrice, StorageUsage};
+
+        let usage = StorageUsage::from_bytes(2_000);
+        assert_eq!(serde_json::to_string(&usage).unwrap(), "2000");
+        assert_eq!(serde_json::from_str::<StorageUsage>("2000").unwrap(), usage);
+        assert_eq!(
+            serde_json::from_str::<StorageUsage>(r#""2000""#).unwrap(),
+            usage
+        );
+        assert_eq!(
+            serde_json::from_str::<StorageUsage>("-1")
+                .unwrap_err()
+                .to_string(),
+            "invalid value: integer `-1`, expected an integer or a string with a number of bytes at line 1 column 2"
+        );
+
+        let price = StoragePrice::DEFAULT;
+        assert_eq!(
+            serde_json::to_string(&price).unwrap(),
+            r#""10000000000000000000""#
+        );
+        assert_eq!(
+            serde_json::from_str::<StoragePrice>(r#""10000000000000000000""#).unwrap(),
+            price
+        );
+    }
 }
@@ -0,0 +1,163 @@
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Decode, Encode, Type};
+
+use crate::UncToken;
+
+/// In PostgreSQL an amount is stored as a `NUMERIC` number of atto-unc, e.g. in a `NUMERIC(39, 0)` column
+/// (`u128::MAX` has 39 digits).
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use sqlx::postgres::types::Oid;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+    use super::*;
+    use crate::trait_impls::pg_numeric::{
+        decode_numeric, decode_numeric_text, encode_numeric, MAX_NUMERIC_LEN,
+    };
+
+    /// The PostgreSQL type OID of `NUMERIC`.
+    const NUMERIC_OID: u32 = 1700;
+
+    impl Type<Postgres> for UncToken {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(Oid(NUMERIC_OID))
+        }
+    }
+
+    impl Encode<'_, Postgres> for UncToken {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            buf.extend_from_slice(encode_numeric(*self, &mut [0; MAX_NUMERIC_LEN]));
+            Ok(IsNull::No)
+        }
+
+        fn size_hint(&self) -> usize {
+            MAX_NUMERIC_LEN
+        }
+    }
+
+    impl Decode<'_, Postgres> for UncToken {
+        fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => decode_numeric(value.as_bytes()?),
+                PgValueFormat::Text => decode_numeric_text(value.as_str()?),
+            }
+        }
+    }
+}
+
+/// In SQLite an amount is stored as the `TEXT` of its number of atto-unc, as SQLite integers only have 64 bits.
+/// Values stored as `INTEGER` are read as well.
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite {
+    use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+    use super::*;
+
+    impl Type<Sqlite> for UncToken {
+        fn type_info() -> SqliteTypeInfo {
+            <str as Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <str as Type<Sqlite>>::compatible(ty) || <i64 as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for UncToken {
+        fn encode_by_ref(
+            &self,
+            buf: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            Encode::<Sqlite>::encode(self.as_attounc().to_string(), buf)
+        }
+    }
+
+    impl Decode<'_, Sqlite> for UncToken {
+        fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+            let text = <&str as Decode<Sqlite>>::decode(value)?;
+            Ok(UncToken::from_attounc(text.parse()?))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sqlx-sqlite"))]
+mod test {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    use sqlx::{Connection, Row, SqliteConnection};
+
+    use crate::UncToken;
+
+    /// Runs `future` on the current thread: the SQLite driver does its work on its own worker thread,
+    /// so no async runtime is needed.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct ThreadWaker(Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn sqlite_round_trip() {
+        block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            sqlx::query("CREATE TABLE balances (id INTEGER PRIMARY KEY, amount TEXT NOT NULL)")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            let amounts = [
+                UncToken::from_attounc(0),
+                UncToken::from_unc(1),
+                UncToken::from_attounc(u128::MAX),
+            ];
+            for amount in amounts {
+                sqlx::query("INSERT INTO balances (amount) VALUES (?)")
+                    .bind(amount)
+                    .execute(&mut conn)
+                    .await
+                    .unwrap();
+            }
+
+            let stored: Vec<UncToken> =
+                sqlx::query_scalar("SELECT amount FROM balances ORDER BY id")
+                    .fetch_all(&mut conn)
+                    .await
+                    .unwrap();
+            assert_eq!(stored, amounts);
+            let text: String = sqlx::query("SELECT amount FROM balances WHERE id = 3")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap()
+                .get(0);
+            assert_eq!(text, u128::MAX.to_string());
+
+            let integer: UncToken = sqlx::query_scalar("SELECT 42")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(integer, UncToken::from_attounc(42));
+            let negative = sqlx::query_scalar::<_, UncToken>("SELECT '-1'")
+                .fetch_one(&mut conn)
+                .await;
+            assert!(negative.is_err());
+        });
+    }
+}
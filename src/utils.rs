@@ -1,99 +1,377 @@
+use core::ops::Range;
+
+use crate::error::{ConstParseError, Diagnostic, ErrorDisplay, InputFragment, ParseErrorKind};
+
 /// Parsing decimal numbers from `&str` type in `u128`.
-/// Function also takes a value of metric prefix in u128 type.
-/// `parse_str` use the `u128` type, and have the same max and min values.
+/// Function also takes the precision exponent: the number of decimal places of the unit, so the number
+/// is multiplied by `10^precision_exponent`, e.g. `parse_decimal_number("1.5", 3)` is `1500`.
+/// `parse_decimal_number` use the `u128` type, and have the same max and min values.
+///
+/// If the fractional part is longer than `precision_exponent` digits, it will return an error of the kind `ParseErrorKind::LongFractional`.
+///
+/// If the string slice has invalid chars, it will return an error of the kind `ParseErrorKind::InvalidNumber`.
 ///
-/// If the fractional part is longer than several zeros in the prefix, it will return the error `DecimalNumberParsingError::LongFractional`.
+/// If the whole part alone multiplied by `10^precision_exponent` is more than the `u128` maximum value,
+/// it will return an error of the kind `ParseErrorKind::LongWhole`; if only the whole number is,
+/// e.g. `parse_decimal_number("0.5", 40)`, it will return an error of the kind `ParseErrorKind::OutOfRange`.
 ///
-/// If the string slice has invalid chars, it will return the error `DecimalNumberParsingError::InvalidNumber`.
+/// Leading and trailing whitespace is ignored. The spans of the errors are byte ranges of `s`. The function is `const`, so it can be used to define constants;
+/// [`ConstParseError::to_decimal_error`] turns its errors into a [`DecimalNumberParsingError`].
 ///
-/// If the whole part of the number has a value more than the `u64` maximum value, it will return the error `DecimalNumberParsingError::LongWhole`.
-pub(crate) fn parse_decimal_number(
+/// # Examples
+/// ```
+/// use unc_token::{parse_decimal_number, DecimalNumberParsingError, ParseErrorKind};
+///
+/// const FEE: u128 = match parse_decimal_number("0.25", 4) {
+///     Ok(fee) => fee,
+///     Err(_) => panic!("invalid fee"),
+/// };
+/// assert_eq!(FEE, 2_500);
+/// let err = parse_decimal_number("0.00001", 4).unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::LongFractional);
+/// assert!(matches!(
+///     err.to_decimal_error(),
+///     DecimalNumberParsingError::LongFractional(_)
+/// ));
+/// ```
+pub const fn parse_decimal_number(
     s: &str,
-    pref_const: u128,
-) -> Result<u128, DecimalNumberParsingError> {
-    let (int, fract) = if let Some((whole, fractional)) = s.trim().split_once('.') {
-        let int: u128 = whole
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        let mut fract: u128 = fractional
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        let len = u32::try_from(fractional.len())
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        fract = fract
-            .checked_mul(
-                pref_const
-                    .checked_div(10u128.checked_pow(len).ok_or_else(|| {
-                        DecimalNumberParsingError::LongFractional(fractional.to_owned())
-                    })?)
-                    .filter(|n| *n != 0u128)
-                    .ok_or_else(|| {
-                        DecimalNumberParsingError::LongFractional(fractional.to_owned())
-                    })?,
-            )
-            .ok_or_else(|| DecimalNumberParsingError::LongFractional(fractional.to_owned()))?;
-        (int, fract)
+    precision_exponent: u32,
+) -> Result<u128, ConstParseError> {
+    let (start, end) = trim_range(s, 0, s.len());
+    match parse_trimmed_decimal_number(substr(s, start, end), precision_exponent) {
+        Ok(value) => Ok(value),
+        Err(err) => Err(err.shifted(start)),
+    }
+}
+
+/// Parses a decimal number without surrounding whitespace, see [`parse_decimal_number`].
+const fn parse_trimmed_decimal_number(
+    s: &str,
+    precision_exponent: u32,
+) -> Result<u128, ConstParseError> {
+    let bytes = s.as_bytes();
+    // Only the whole part may have a sign, as in `u128::from_str`.
+    let sign_len = if !bytes.is_empty() && bytes[0] == b'+' {
+        1
     } else {
-        let int: u128 = s
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        (int, 0)
+        0
+    };
+    let mut dot = 0;
+    while dot < bytes.len() && bytes[dot] != b'.' {
+        dot += 1;
+    }
+    let int = match parse_digits(bytes, sign_len, dot) {
+        Some(int) => int,
+        None => return Err(invalid_number(s)),
+    };
+    let int = match checked_mul_pow10(int, precision_exponent) {
+        Some(int) => int,
+        None => {
+            return Err(ConstParseError::new(
+                ParseErrorKind::LongWhole,
+                0..dot,
+                0..dot,
+            ))
+        }
+    };
+    // The whole part fits, so an overflow from here on is caused by the number as a whole.
+    let out_of_range = ConstParseError::new(ParseErrorKind::OutOfRange, 0..s.len(), 0..s.len());
+    let mut fract = 0;
+    if dot < bytes.len() {
+        fract = match parse_digits(bytes, dot + 1, bytes.len()) {
+            Some(fract) => fract,
+            None => return Err(invalid_number(s)),
+        };
+        let len = bytes.len() - dot - 1;
+        if len > u32::MAX as usize {
+            return Err(invalid_number(s));
+        }
+        if len as u32 > precision_exponent {
+            return Err(ConstParseError::new(
+                ParseErrorKind::LongFractional,
+                dot + 1..s.len(),
+                dot + 1..s.len(),
+            ));
+        }
+        fract = match checked_mul_pow10(fract, precision_exponent - len as u32) {
+            Some(fract) => fract,
+            None => return Err(out_of_range),
+        };
+    }
+    match fract.checked_add(int) {
+        Some(result) => Ok(result),
+        None => Err(out_of_range),
+    }
+}
+
+/// [`parse_decimal_number`] with the error of the `FromStr` implementations.
+pub(crate) fn parse_decimal(
+    s: &str,
+    precision_exponent: u32,
+) -> Result<u128, DecimalNumberParsingError> {
+    parse_decimal_number(s, precision_exponent).map_err(|err| err.to_decimal_error())
+}
+
+/// Computes `value * 10^exponent`, returning None on overflow.
+const fn checked_mul_pow10(value: u128, exponent: u32) -> Option<u128> {
+    if value == 0 {
+        return Some(0);
+    }
+    match 10u128.checked_pow(exponent) {
+        Some(multiplier) => value.checked_mul(multiplier),
+        None => None,
+    }
+}
+
+/// Parses `bytes[start..end]` as at least one decimal digit, without a sign and without overflowing `u128`.
+const fn parse_digits(bytes: &[u8], start: usize, end: usize) -> Option<u128> {
+    let mut index = start;
+    if index == end {
+        return None;
+    }
+    let mut value: u128 = 0;
+    while index < end {
+        let digit = bytes[index];
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((digit - b'0') as u128) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        index += 1;
+    }
+    Some(value)
+}
+
+/// Returns the `InvalidNumber` error that points at the first character of `s` that cannot appear
+/// in a decimal number, or at the whole `s` if there is no such character (e.g. a missing whole or fractional part).
+const fn invalid_number(s: &str) -> ConstParseError {
+    let bytes = s.as_bytes();
+    let mut seen_dot = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'0'..=b'9' => {}
+            b'+' if index == 0 => {}
+            b'.' if !seen_dot => seen_dot = true,
+            byte => {
+                return ConstParseError::new(
+                    ParseErrorKind::InvalidNumber,
+                    0..s.len(),
+                    index..index + char_len(byte),
+                )
+            }
+        }
+        index += 1;
+    }
+    ConstParseError::new(ParseErrorKind::InvalidNumber, 0..s.len(), 0..s.len())
+}
+
+/// Returns the length in bytes of the UTF-8 encoded character that starts with `first_byte`.
+pub(crate) const fn char_len(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+/// Returns `&s[start..end]`; `start` and `end` must lie on character boundaries.
+pub(crate) const fn substr(s: &str, start: usize, end: usize) -> &str {
+    let (bytes, _) = s.as_bytes().split_at(end);
+    let (_, bytes) = bytes.split_at(start);
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("the range does not lie on character boundaries"),
+    }
+}
+
+/// Returns the byte range of `s[start..end]` without leading and trailing whitespace, like `str::trim` does.
+pub(crate) const fn trim_range(s: &str, mut start: usize, mut end: usize) -> (usize, usize) {
+    let bytes = s.as_bytes();
+    while start < end {
+        let (c, len) = decode_char(bytes, start);
+        if !is_whitespace(c) {
+            break;
+        }
+        start += len;
+    }
+    while end > start {
+        let mut char_start = end - 1;
+        // Step back over UTF-8 continuation bytes.
+        while (bytes[char_start] as i8) < -0x40 {
+            char_start -= 1;
+        }
+        let (c, _) = decode_char(bytes, char_start);
+        if !is_whitespace(c) {
+            break;
+        }
+        end = char_start;
+    }
+    (start, end)
+}
+
+/// Decodes the UTF-8 encoded character that starts at `bytes[index]`, returning its code point and length in bytes.
+pub(crate) const fn decode_char(bytes: &[u8], index: usize) -> (u32, usize) {
+    let len = char_len(bytes[index]);
+    let mut c = match len {
+        1 => return (bytes[index] as u32, 1),
+        2 => bytes[index] as u32 & 0x1f,
+        3 => bytes[index] as u32 & 0x0f,
+        _ => bytes[index] as u32 & 0x07,
     };
-    let result = fract
-        .checked_add(
-            int.checked_mul(pref_const)
-                .ok_or_else(|| DecimalNumberParsingError::LongWhole(int.to_string()))?,
-        )
-        .ok_or_else(|| DecimalNumberParsingError::LongWhole(int.to_string()))?;
-    Ok(result)
+    let mut i = 1;
+    while i < len {
+        c = (c << 6) | (bytes[index + i] as u32 & 0x3f);
+        i += 1;
+    }
+    (c, len)
+}
+
+/// The same set of characters as `char::is_whitespace` (the Unicode `White_Space` property).
+pub(crate) const fn is_whitespace(c: u32) -> bool {
+    matches!(
+        c,
+        0x09..=0x0d
+            | 0x20
+            | 0x85
+            | 0xa0
+            | 0x1680
+            | 0x2000..=0x200a
+            | 0x2028
+            | 0x2029
+            | 0x202f
+            | 0x205f
+            | 0x3000
+    )
+}
+
+/// `str::eq_ignore_ascii_case` that can be used in const contexts.
+pub(crate) const fn eq_ignore_ascii_case(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < lhs.len() {
+        if !lhs[index].eq_ignore_ascii_case(&rhs[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DecimalNumberParsingError {
-    InvalidNumber(String),
-    LongWhole(String),
-    LongFractional(String),
+    InvalidNumber(InputFragment),
+    LongWhole(InputFragment),
+    LongFractional(InputFragment),
+    /// The number does not fit into `u128` after it is multiplied by `10^precision_exponent`,
+    /// while its whole part alone does.
+    OutOfRange(InputFragment),
 }
 
-impl std::error::Error for DecimalNumberParsingError {}
+impl DecimalNumberParsingError {
+    /// `span` is a function that shows the byte range of the parsed input that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        self.fragment().span()
+    }
+
+    /// `display` is a function that shows the error with the quoted part of the parsed `input`.
+    pub fn display<'a>(&'a self, input: &'a str) -> ErrorDisplay<'a> {
+        ErrorDisplay::decimal(self, input)
+    }
+
+    /// `diagnostic` is a function that renders the error with the parsed `input` and a caret line under the [`span`](Self::span).
+    pub fn diagnostic<'a>(&'a self, input: &'a str) -> Diagnostic<'a> {
+        Diagnostic::new(self.display(input))
+    }
 
-impl std::fmt::Display for DecimalNumberParsingError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fragment(&self) -> &InputFragment {
         match self {
-            DecimalNumberParsingError::InvalidNumber(s) => {
-                write!(f, "invalid number: {}", s)
+            DecimalNumberParsingError::InvalidNumber(fragment)
+            | DecimalNumberParsingError::LongWhole(fragment)
+            | DecimalNumberParsingError::LongFractional(fragment)
+            | DecimalNumberParsingError::OutOfRange(fragment) => fragment,
+        }
+    }
+
+    pub(crate) fn shifted(self, offset: usize) -> Self {
+        match self {
+            DecimalNumberParsingError::InvalidNumber(fragment) => {
+                DecimalNumberParsingError::InvalidNumber(fragment.shifted(offset))
             }
-            DecimalNumberParsingError::LongWhole(s) => {
-                write!(f, "too long whole part: {}", s)
+            DecimalNumberParsingError::LongWhole(fragment) => {
+                DecimalNumberParsingError::LongWhole(fragment.shifted(offset))
             }
-            DecimalNumberParsingError::LongFractional(s) => {
-                write!(f, "too long fractional part: {}", s)
+            DecimalNumberParsingError::LongFractional(fragment) => {
+                DecimalNumberParsingError::LongFractional(fragment.shifted(offset))
+            }
+            DecimalNumberParsingError::OutOfRange(fragment) => {
+                DecimalNumberParsingError::OutOfRange(fragment.shifted(offset))
             }
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for DecimalNumberParsingError {}
+
+impl DecimalNumberParsingError {
+    pub(crate) fn fmt_with(
+        &self,
+        input: Option<&str>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        f.write_str(match self {
+            DecimalNumberParsingError::InvalidNumber(_) => "invalid number",
+            DecimalNumberParsingError::LongWhole(_) => "too long whole part",
+            DecimalNumberParsingError::LongFractional(_) => "too long fractional part",
+            DecimalNumberParsingError::OutOfRange(_) => "number is out of range",
+        })?;
+        self.fragment().fmt_with(input, f)
+    }
+}
+
+impl core::fmt::Display for DecimalNumberParsingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_with(None, f)
+    }
+}
+
+/// Returns the byte offset of `part` inside `whole`; `part` must be a subslice of `whole`.
+pub(crate) fn offset_in(whole: &str, part: &str) -> usize {
+    part.as_ptr() as usize - whole.as_ptr() as usize
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST: [(u128, &'static str, u128); 6] = [
-        (129380_000_001u128, "129.380000001", 10u128.pow(9)),
-        (
-            12938_000_000_100_000_000u128,
-            "12938000000.1",
-            10u128.pow(9),
-        ),
-        (129380_000_001u128, "0.129380000001", 10u128.pow(12)),
-        (129380_000_001_000u128, "129.380000001000", 10u128.pow(12)),
-        (9488129380_000_001u128, "9488.129380000001", 10u128.pow(12)),
-        (129380_000_001u128, "00.129380000001", 10u128.pow(12)),
+    #[allow(
+        clippy::redundant_static_lifetimes,
+        clippy::inconsistent_digit_grouping
+    )]
+    const TEST: [(u128, &'static str, u32); 6] = [
+        (129380_000_001u128, "129.380000001", 9),
+        (12938_000_000_100_000_000u128, "12938000000.1", 9),
+        (129380_000_001u128, "0.129380000001", 12),
+        (129380_000_001_000u128, "129.380000001000", 12),
+        (9488129380_000_001u128, "9488.129380000001", 12),
+        (129380_000_001u128, "00.129380000001", 12),
     ];
 
     #[test]
     fn parse_test() {
         for (expected_value, str_value, precision) in TEST {
-            let parsed_value = parse_decimal_number(str_value, precision).unwrap();
+            let parsed_value = parse_decimal(str_value, precision).unwrap();
             assert_eq!(parsed_value, expected_value)
         }
     }
@@ -101,32 +379,34 @@ mod tests {
     #[test]
     fn test_long_fract() {
         let data = "1.23456";
-        let prefix = 10000u128;
+        let precision = 4;
         assert_eq!(
-            parse_decimal_number(data, prefix),
-            Err(DecimalNumberParsingError::LongFractional(23456.to_string()))
+            parse_decimal(data, precision),
+            Err(DecimalNumberParsingError::LongFractional(
+                InputFragment::new(2..7, 2..7)
+            ))
         );
     }
 
     #[test]
     fn invalidnumber_whole() {
         let num = "1h4.7859";
-        let prefix: u128 = 10000;
+        let precision = 4;
         assert_eq!(
-            parse_decimal_number(num, prefix),
+            parse_decimal(num, precision),
             Err(DecimalNumberParsingError::InvalidNumber(
-                "1h4.7859".to_owned()
+                InputFragment::new(0..8, 1..2)
             ))
         );
     }
     #[test]
     fn invalidnumber_fract() {
         let num = "14.785h9";
-        let prefix: u128 = 10000;
+        let precision = 4;
         assert_eq!(
-            parse_decimal_number(num, prefix),
+            parse_decimal(num, precision),
             Err(DecimalNumberParsingError::InvalidNumber(
-                "14.785h9".to_owned()
+                InputFragment::new(0..8, 6..7)
             ))
         );
     }
@@ -135,11 +415,11 @@ mod tests {
     fn max_long_fract() {
         let max_data = 10u128.pow(17) + 1;
         let data = "1.".to_string() + max_data.to_string().as_str();
-        let prefix = 10u128.pow(17);
+        let precision = 17;
         assert_eq!(
-            parse_decimal_number(data.as_str(), prefix),
+            parse_decimal(data.as_str(), precision),
             Err(DecimalNumberParsingError::LongFractional(
-                max_data.to_string()
+                InputFragment::new(2..data.len(), 2..data.len())
             ))
         );
     }
@@ -147,22 +427,83 @@ mod tests {
     #[test]
     fn parse_u128_errortest() {
         let test_data = u128::MAX.to_string();
-        let gas = parse_decimal_number(&test_data, 10u128.pow(9));
+        let gas = parse_decimal(&test_data, 9);
         assert_eq!(
             gas,
-            Err(DecimalNumberParsingError::LongWhole(u128::MAX.to_string()))
+            Err(DecimalNumberParsingError::LongWhole(InputFragment::new(
+                0..test_data.len(),
+                0..test_data.len()
+            )))
         );
     }
 
     #[test]
     fn test() {
         let data = "1.000000000000000000000000000000000000001";
-        let prefix = 100u128;
+        let precision = 2;
         assert_eq!(
-            parse_decimal_number(data, prefix),
+            parse_decimal(data, precision),
             Err(DecimalNumberParsingError::LongFractional(
-                "000000000000000000000000000000000000001".to_string()
+                InputFragment::new(2..data.len(), 2..data.len())
+            ))
+        );
+    }
+
+    #[test]
+    fn surrounding_whitespace() {
+        assert_eq!(parse_decimal(" 1.5\t", 1), Ok(15));
+        assert_eq!(parse_decimal_number("\u{a0}1.5 ", 1), Ok(15));
+        assert_eq!(
+            parse_decimal(" 1.5", 0),
+            Err(DecimalNumberParsingError::LongFractional(
+                InputFragment::new(3..4, 3..4)
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_const() {
+        const PARSED: [Result<u128, ConstParseError>; 3] = [
+            parse_decimal_number("2.5", 4),
+            parse_decimal_number("+1.5", 2),
+            parse_decimal_number("0.1", 0),
+        ];
+        assert_eq!(PARSED[0], Ok(25_000));
+        assert_eq!(PARSED[1], Ok(150));
+        assert_eq!(
+            PARSED[2],
+            Err(ConstParseError::new(
+                ParseErrorKind::LongFractional,
+                2..3,
+                2..3
             ))
         );
     }
+
+    #[test]
+    fn large_precision_exponent() {
+        assert_eq!(parse_decimal("0", 100), Ok(0));
+        assert_eq!(parse_decimal("0.1", 39), Ok(10u128.pow(38)));
+        assert_eq!(
+            parse_decimal("0.5", 40),
+            Err(DecimalNumberParsingError::OutOfRange(InputFragment::new(
+                0..3,
+                0..3
+            )))
+        );
+        assert_eq!(
+            parse_decimal("34028236692093846346337460743176821145.6", 1),
+            Err(DecimalNumberParsingError::OutOfRange(InputFragment::new(
+                0..40,
+                0..40
+            )))
+        );
+        assert_eq!(
+            parse_decimal("1", 39),
+            Err(DecimalNumberParsingError::LongWhole(InputFragment::new(
+                0..1,
+                0..1
+            )))
+        );
+    }
 }